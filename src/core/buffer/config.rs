@@ -0,0 +1,182 @@
+use core::{num::NonZeroUsize, time::Duration};
+
+/// How [`Buffer::get`](super::Buffer::get) serves a timestamp between stored
+/// samples.
+///
+/// Every policy answers only within the covered range (there is no
+/// extrapolation) and stamps the result with the requested time, so buffers
+/// with different policies still compose in one lookup chain. Static buffers
+/// return their transform for any timestamp regardless of the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum InterpolationPolicy {
+    /// Interpolates between the neighboring samples: lerp for the
+    /// translation, slerp for the rotation.
+    #[default]
+    Linear,
+
+    /// Uses the sample closest in time; of two equally close samples, the
+    /// earlier one.
+    Nearest,
+
+    /// Holds the latest sample at or before the requested time.
+    Hold,
+
+    /// Serves only timestamps with a sample stored at exactly that time.
+    None,
+}
+
+/// Retention and lookup settings for a [`Buffer`](super::Buffer).
+///
+/// The default configuration keeps every entry until it is removed
+/// manually and interpolates linearly. Both limits are data-driven and
+/// enforced whenever a dynamic transform is inserted: `max_age` removes
+/// entries older than the latest inserted timestamp minus `max_age`, and
+/// `max_entries` then evicts the oldest entries until at most `max_entries`
+/// remain. Static buffers hold a single transform and are never trimmed.
+///
+/// # Examples
+///
+/// ```
+/// use core::{num::NonZeroUsize, time::Duration};
+/// use transforms::core::{Buffer, BufferConfig};
+///
+/// let config = BufferConfig::new()
+///     .with_max_age(Duration::from_secs(10))
+///     .with_max_entries(NonZeroUsize::new(1000).unwrap());
+/// let buffer: Buffer = Buffer::with_config(config);
+///
+/// assert_eq!(buffer.config().max_age(), Some(Duration::from_secs(10)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BufferConfig {
+    max_age: Option<Duration>,
+    max_entries: Option<NonZeroUsize>,
+    interpolation: InterpolationPolicy,
+    strict_ordering: bool,
+    max_interpolation_gap: Option<Duration>,
+}
+
+impl BufferConfig {
+    /// Creates a configuration without any retention limit.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_age: None,
+            max_entries: None,
+            interpolation: InterpolationPolicy::Linear,
+            strict_ordering: false,
+            max_interpolation_gap: None,
+        }
+    }
+
+    /// Returns the configuration with entries expiring after `max_age`,
+    /// relative to the latest inserted timestamp.
+    #[must_use]
+    pub const fn with_max_age(
+        self,
+        max_age: Duration,
+    ) -> Self {
+        Self {
+            max_age: Some(max_age),
+            ..self
+        }
+    }
+
+    /// Returns the configuration with at most `max_entries` entries retained;
+    /// the oldest entries are evicted first.
+    #[must_use]
+    pub const fn with_max_entries(
+        self,
+        max_entries: NonZeroUsize,
+    ) -> Self {
+        Self {
+            max_entries: Some(max_entries),
+            ..self
+        }
+    }
+
+    /// Returns the configuration with the given interpolation policy.
+    #[must_use]
+    pub const fn with_interpolation(
+        self,
+        interpolation: InterpolationPolicy,
+    ) -> Self {
+        Self {
+            interpolation,
+            ..self
+        }
+    }
+
+    /// Returns the configuration with strict ordering of dynamic inserts
+    /// turned on or off.
+    ///
+    /// With strict ordering, inserting a dynamic transform older than the
+    /// newest stored sample fails with
+    /// [`BufferError::TransformOlderThanLatest`](crate::errors::BufferError::TransformOlderThanLatest)
+    /// instead of being stored in between, which surfaces publishers with
+    /// misconfigured clocks. A sample at the newest timestamp still replaces
+    /// it.
+    #[must_use]
+    pub const fn with_strict_ordering(
+        self,
+        strict_ordering: bool,
+    ) -> Self {
+        Self {
+            strict_ordering,
+            ..self
+        }
+    }
+
+    /// Returns the configuration with a maximum gap between the two samples
+    /// a timestamp is served from.
+    ///
+    /// A timestamp between two stored samples further apart than
+    /// `max_interpolation_gap` fails with
+    /// [`BufferError::InterpolationGapTooLarge`](crate::errors::BufferError::InterpolationGapTooLarge)
+    /// instead of being bridged, which catches a source that dropped out
+    /// for seconds and came back. The limit applies to every policy that
+    /// bridges samples (`Linear`, `Nearest` and `Hold`); timestamps with a
+    /// sample stored at exactly that time are always served.
+    #[must_use]
+    pub const fn with_max_interpolation_gap(
+        self,
+        max_interpolation_gap: Duration,
+    ) -> Self {
+        Self {
+            max_interpolation_gap: Some(max_interpolation_gap),
+            ..self
+        }
+    }
+
+    /// Returns the configured maximum age, if any.
+    #[must_use]
+    pub const fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Returns the configured maximum number of entries, if any.
+    #[must_use]
+    pub const fn max_entries(&self) -> Option<NonZeroUsize> {
+        self.max_entries
+    }
+
+    /// Returns the configured interpolation policy.
+    #[must_use]
+    pub const fn interpolation(&self) -> InterpolationPolicy {
+        self.interpolation
+    }
+
+    /// Returns `true` if dynamic inserts must not be older than the newest
+    /// stored sample.
+    #[must_use]
+    pub const fn strict_ordering(&self) -> bool {
+        self.strict_ordering
+    }
+
+    /// Returns the configured maximum interpolation gap, if any.
+    #[must_use]
+    pub const fn max_interpolation_gap(&self) -> Option<Duration> {
+        self.max_interpolation_gap
+    }
+}
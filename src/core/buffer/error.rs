@@ -25,9 +25,9 @@ pub enum BufferError {
     #[error("a frame cannot be its own parent")]
     SelfReferentialFrame,
 
-    /// The child frame already has a different parent. Re-parenting is not
-    /// supported; remove the frame first (`Registry::remove_frame`) and
-    /// re-add it under its new parent.
+    /// The child frame already has a different parent. Inserts cannot
+    /// re-parent a frame; use `Registry::reparent`, or remove the frame
+    /// first (`Registry::remove_frame`) and re-add it under its new parent.
     #[error("re-parenting is not supported (the child frame's parent is {0})")]
     ReparentingNotSupported(String),
 
@@ -42,7 +42,74 @@ pub enum BufferError {
     #[error("inserting the transform would create a cycle in the frame tree")]
     CycleDetected,
 
+    /// An exact lookup found no transform stored at exactly the requested
+    /// timestamp (in seconds). Exact lookups never interpolate.
+    #[error("no transform is stored at exactly {0}")]
+    NoExactMatch(f64),
+
+    /// A nearest-sample lookup found no transform within the tolerance of
+    /// the requested timestamp (in seconds).
+    #[error("no transform is stored within the tolerance of {0}")]
+    NoSampleWithinTolerance(f64),
+
+    /// The frame to rename exists nowhere in the registry, neither as a
+    /// child nor as a parent frame.
+    #[error("frame {0} does not exist in the registry")]
+    UnknownFrame(String),
+
+    /// The new frame name is already in use. Renaming onto an existing
+    /// frame would merge two frames and could corrupt the tree.
+    #[error("frame {0} already exists in the registry")]
+    FrameExists(String),
+
+    /// A buffer with strict ordering was given a dynamic transform older than
+    /// its newest stored sample (in seconds: the transform's timestamp, the
+    /// newest stored timestamp).
+    #[error("transform at {0} is older than the newest stored sample at {1}")]
+    TransformOlderThanLatest(f64, f64),
+
+    /// The requested timestamp lies between two stored samples further
+    /// apart than the buffer's maximum interpolation gap (in seconds: the
+    /// requested time, the earlier sample, the later sample).
+    #[error("samples at {1} and {2} around {0} are too far apart to interpolate")]
+    InterpolationGapTooLarge(f64, f64, f64),
+
     /// A transform operation failed during retrieval.
     #[error("transform error: {0}")]
     TransformError(#[from] TransformError),
 }
+
+impl BufferError {
+    /// Returns the stable numeric code of the error variant.
+    ///
+    /// Codes never change between releases and are never reused, so they
+    /// can be logged, stored, or matched on across crate versions. Wrapping
+    /// variants report their own code; inspect the wrapped error for its
+    /// cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::errors::BufferError;
+    ///
+    /// assert_eq!(BufferError::CycleDetected.code(), 205);
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::NoTransformAvailable => 200,
+            Self::StaticDynamicConflict => 201,
+            Self::SelfReferentialFrame => 202,
+            Self::ReparentingNotSupported(_) => 203,
+            Self::ChildFrameMismatch(_) => 204,
+            Self::CycleDetected => 205,
+            Self::NoExactMatch(_) => 206,
+            Self::NoSampleWithinTolerance(_) => 207,
+            Self::UnknownFrame(_) => 208,
+            Self::FrameExists(_) => 209,
+            Self::TransformError(_) => 210,
+            Self::TransformOlderThanLatest(..) => 211,
+            Self::InterpolationGapTooLarge(..) => 212,
+        }
+    }
+}
@@ -29,6 +29,9 @@
 //!   - Buffers created with `Buffer::new` never expire entries; use the `delete_before`
 //!     method for manual cleanup. Static transforms never expire and survive manual
 //!     cleanup.
+//!   - A [`BufferConfig`] passed to `Buffer::with_config` can additionally cap the
+//!     number of entries, evicting the oldest ones first. This bounds memory when a
+//!     source bursts at rates the age limit alone does not cover.
 //!
 //! # Examples
 //!
@@ -81,9 +84,14 @@ use crate::{
     geometry::Transform,
     time::{TimePoint, Timestamp},
 };
-use alloc::{collections::BTreeMap, string::String};
-use core::time::Duration;
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String};
+pub use config::{BufferConfig, InterpolationPolicy};
+use core::{
+    ops::{Bound, RangeBounds},
+    time::Duration,
+};
 pub use error::BufferError;
+mod config;
 mod error;
 
 type NearestTransforms<'a, T> = (
@@ -112,23 +120,33 @@ type NearestTransforms<'a, T> = (
 /// `max_age` relative to the latest inserted timestamp are removed
 /// automatically on insert. A buffer created with [`Buffer::new`] never
 /// expires entries; use [`Buffer::delete_before`] for manual cleanup.
+/// [`Buffer::with_config`] accepts a [`BufferConfig`], which can also cap
+/// the number of retained entries.
 #[derive(Debug)]
 pub struct Buffer<T = Timestamp>
 where
     T: TimePoint,
 {
     data: BTreeMap<T, Transform<T>>,
-    max_age: Option<Duration>,
+    config: BufferConfig,
     latest_timestamp: Option<T>,
     is_static: bool,
     parent: Option<String>,
     child: Option<String>,
+    authority: Option<String>,
+    /// Moving average of the stamp interval between in-order inserts, in
+    /// seconds.
+    mean_interval: Option<f64>,
 }
 
 impl<T> Buffer<T>
 where
     T: TimePoint,
 {
+    /// The weight of the newest interval in the moving average behind
+    /// [`Buffer::mean_interval`] and [`Buffer::rate`].
+    pub const RATE_SMOOTHING: f64 = 0.1;
+
     /// Creates a new `Buffer` without automatic expiry.
     ///
     /// Entries are kept until removed manually with
@@ -142,14 +160,7 @@ where
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            data: BTreeMap::new(),
-            max_age: None,
-            latest_timestamp: None,
-            is_static: false,
-            parent: None,
-            child: None,
-        }
+        Self::with_config(BufferConfig::new())
     }
 
     /// Creates a new `Buffer` with automatic expiry after `max_age`.
@@ -169,16 +180,40 @@ where
     /// ```
     #[must_use]
     pub fn with_max_age(max_age: Duration) -> Self {
+        Self::with_config(BufferConfig::new().with_max_age(max_age))
+    }
+
+    /// Creates a new `Buffer` with the given retention settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use transforms::core::{Buffer, BufferConfig};
+    ///
+    /// let config = BufferConfig::new().with_max_entries(NonZeroUsize::new(100).unwrap());
+    /// let buffer: Buffer = Buffer::with_config(config);
+    /// ```
+    #[must_use]
+    pub fn with_config(config: BufferConfig) -> Self {
         Self {
             data: BTreeMap::new(),
-            max_age: Some(max_age),
+            config,
             latest_timestamp: None,
             is_static: false,
             parent: None,
             child: None,
+            authority: None,
+            mean_interval: None,
         }
     }
 
+    /// Returns the buffer's retention settings.
+    #[must_use]
+    pub fn config(&self) -> BufferConfig {
+        self.config
+    }
+
     /// Returns the buffer's parent frame, pinned by the first insert.
     ///
     /// `None` for a buffer that has never held a transform. The parent stays
@@ -199,12 +234,347 @@ where
         self.child.as_deref()
     }
 
+    /// Returns the authority that wrote the most recent transform, if it
+    /// was given one.
+    ///
+    /// Set by `Registry::add_transform_with_authority`; an insert without an
+    /// authority clears it, so the value always describes the latest write.
+    #[must_use]
+    pub fn authority(&self) -> Option<&str> {
+        self.authority.as_deref()
+    }
+
+    /// Returns the smoothed interval between incoming samples.
+    ///
+    /// The interval is measured between the stamps of consecutive inserts
+    /// that advance the newest timestamp, not by a clock, and smoothed with
+    /// an exponential moving average (weight [`Buffer::RATE_SMOOTHING`] for
+    /// the newest interval). `None` until two dynamic samples have arrived
+    /// in order. Deleting transforms forgets the average when it rewinds the
+    /// newest timestamp or empties the buffer, so the rate always describes
+    /// the samples still stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     core::Buffer,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut buffer = Buffer::new();
+    /// for millis in [0, 100, 200, 300] {
+    ///     buffer
+    ///         .insert(Transform {
+    ///             translation: Vector3::zero(),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_millis(1_000 + millis),
+    ///             parent: "odom".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert_eq!(buffer.mean_interval(), Some(Duration::from_millis(100)));
+    /// assert!((buffer.rate().unwrap() - 10.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn mean_interval(&self) -> Option<Duration> {
+        self.mean_interval
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+    }
+
+    /// Returns the smoothed update rate in hertz, the inverse of
+    /// [`Buffer::mean_interval`].
+    ///
+    /// `None` until two dynamic samples have arrived in order, or if they
+    /// share a timestamp.
+    #[must_use]
+    pub fn rate(&self) -> Option<f64> {
+        self.mean_interval
+            .filter(|&seconds| seconds > 0.0)
+            .map(|seconds| 1.0 / seconds)
+    }
+
+    /// Returns the number of transforms in the buffer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
     /// Returns `true` if the buffer holds no transforms.
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
 
+    /// Returns `true` if the buffer holds a static transform, valid for all
+    /// time. An empty buffer is neither static nor dynamic.
+    #[must_use]
+    pub fn is_static(&self) -> bool {
+        self.is_static && !self.data.is_empty()
+    }
+
+    /// Returns the timestamp of the oldest stored transform.
+    ///
+    /// `None` for an empty buffer. A static buffer returns the static
+    /// timestamp, although its transform is valid for any requested time.
+    #[must_use]
+    pub fn oldest(&self) -> Option<T> {
+        self.data.first_key_value().map(|(&k, _)| k)
+    }
+
+    /// Returns the timestamp of the newest stored transform.
+    ///
+    /// `None` for an empty buffer. A static buffer returns the static
+    /// timestamp, although its transform is valid for any requested time.
+    #[must_use]
+    pub fn newest(&self) -> Option<T> {
+        self.data.last_key_value().map(|(&k, _)| k)
+    }
+
+    /// Retrieves the transform stored at exactly the given timestamp.
+    ///
+    /// Unlike [`Buffer::get`], this never interpolates, for pipelines where
+    /// interpolation is semantically wrong, such as discrete keyframes. A
+    /// static buffer is valid for all time and returns its transform for any
+    /// timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BufferError::NoTransformAvailable` if the buffer holds no
+    /// transforms, and `BufferError::NoExactMatch` if no transform is stored
+    /// at the timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     core::Buffer,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut buffer = Buffer::new();
+    /// for secs in [1, 3] {
+    ///     buffer
+    ///         .insert(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert!(
+    ///     buffer
+    ///         .get_exact(&Timestamp::from_nanos(1_000_000_000))
+    ///         .is_ok()
+    /// );
+    /// assert!(
+    ///     buffer
+    ///         .get_exact(&Timestamp::from_nanos(2_000_000_000))
+    ///         .is_err()
+    /// );
+    /// ```
+    pub fn get_exact(
+        &self,
+        timestamp: &T,
+    ) -> Result<Transform<T>, BufferError> {
+        if self.is_static {
+            return self.get(timestamp);
+        }
+        if self.data.is_empty() {
+            return Err(BufferError::NoTransformAvailable);
+        }
+        self.data
+            .get(timestamp)
+            .cloned()
+            .ok_or_else(|| BufferError::NoExactMatch(timestamp.as_seconds_lossy()))
+    }
+
+    /// Returns the most recent stored transform.
+    ///
+    /// `None` for an empty buffer. The transform is returned as stored, with
+    /// its own timestamp; a static buffer returns its static transform.
+    #[must_use]
+    pub fn get_latest(&self) -> Option<&Transform<T>> {
+        self.data.last_key_value().map(|(_, tf)| tf)
+    }
+
+    /// Returns the oldest stored transform.
+    ///
+    /// `None` for an empty buffer. The transform is returned as stored, with
+    /// its own timestamp; a static buffer returns its static transform.
+    #[must_use]
+    pub fn get_oldest(&self) -> Option<&Transform<T>> {
+        self.data.first_key_value().map(|(_, tf)| tf)
+    }
+
+    /// Returns the stored transforms immediately before and after
+    /// `timestamp`, the samples [`Buffer::get`] interpolates between.
+    ///
+    /// The first is the newest sample at or before the timestamp, the second
+    /// the oldest at or after it; a sample stored at exactly the timestamp
+    /// is both. Either is `None` beyond the covered range. Both are returned
+    /// as stored, for callers applying their own interpolation or validity
+    /// checks. A static buffer returns its transform for any timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     core::Buffer,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut buffer = Buffer::new();
+    /// for secs in [1, 3] {
+    ///     buffer
+    ///         .insert(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let (before, after) = buffer.get_bounds(&Timestamp::from_nanos(2_000_000_000));
+    /// assert_eq!(before.unwrap().translation.x, 1.0);
+    /// assert_eq!(after.unwrap().translation.x, 3.0);
+    ///
+    /// let (before, after) = buffer.get_bounds(&Timestamp::from_nanos(4_000_000_000));
+    /// assert_eq!(before.unwrap().translation.x, 3.0);
+    /// assert!(after.is_none());
+    /// ```
+    #[must_use]
+    pub fn get_bounds(
+        &self,
+        timestamp: &T,
+    ) -> (Option<&Transform<T>>, Option<&Transform<T>>) {
+        if self.is_static {
+            let tf = self.data.get(&T::static_timestamp());
+            return (tf, tf);
+        }
+        let (before, after) = self.get_nearest(timestamp);
+        (before.map(|(_, tf)| tf), after.map(|(_, tf)| tf))
+    }
+
+    /// Returns an iterator over the stored transforms with timestamps within
+    /// `range`, in timestamp order.
+    ///
+    /// The transforms are borrowed as stored, without interpolation, which
+    /// allows trajectory extraction or custom smoothing without copying the
+    /// buffer. A range whose start lies after its end yields nothing. A
+    /// static buffer's transform is yielded only if the range contains the
+    /// static timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     core::Buffer,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut buffer = Buffer::new();
+    /// for secs in 1..=4 {
+    ///     buffer
+    ///         .insert(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let start = Timestamp::from_nanos(2_000_000_000);
+    /// let end = Timestamp::from_nanos(4_000_000_000);
+    /// let xs: Vec<f64> = buffer
+    ///     .range(start..end)
+    ///     .map(|tf| tf.translation.x)
+    ///     .collect();
+    /// assert_eq!(xs, [2.0, 3.0]);
+    /// ```
+    pub fn range<R>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = &Transform<T>>
+    where
+        R: RangeBounds<T>,
+    {
+        // `BTreeMap::range` panics on inverted or empty-exclusive bounds.
+        let valid = match (range.start_bound(), range.end_bound()) {
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) if start > end => false,
+            (Bound::Excluded(start), Bound::Excluded(end)) => start != end,
+            _ => true,
+        };
+        valid
+            .then(|| self.data.range(range))
+            .into_iter()
+            .flatten()
+            .map(|(_, tf)| tf)
+    }
+
+    /// Returns the time covered by the buffer, from the oldest to the newest
+    /// stored transform.
+    ///
+    /// [`Buffer::get`] serves any timestamp within this span. A buffer with a
+    /// single transform, including every static buffer, has a span of zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BufferError::NoTransformAvailable` if the buffer is empty, and
+    /// `BufferError::TransformError` wrapping the `TimeError` if the span
+    /// cannot be represented as a `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     core::Buffer,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut buffer = Buffer::new();
+    /// for secs in [1, 3] {
+    ///     buffer
+    ///         .insert(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert_eq!(buffer.time_span().unwrap(), Duration::from_secs(2));
+    /// ```
+    pub fn time_span(&self) -> Result<Duration, BufferError> {
+        match (self.oldest(), self.newest()) {
+            (Some(oldest), Some(newest)) => newest
+                .duration_since(oldest)
+                .map_err(|e| BufferError::TransformError(e.into())),
+            _ => Err(BufferError::NoTransformAvailable),
+        }
+    }
+
     /// Adds a transform to the buffer.
     ///
     /// The transform is validated first: it must have finite components and
@@ -234,6 +604,10 @@ where
     /// child frame would silently overwrite a static transform or corrupt
     /// interpolation between dynamic ones.
     ///
+    /// With [`BufferConfig::with_strict_ordering`], returns
+    /// `BufferError::TransformOlderThanLatest` if a dynamic transform is
+    /// older than the newest stored sample.
+    ///
     /// # Examples
     ///
     /// ```
@@ -302,10 +676,21 @@ where
         } else if self.is_static != is_static {
             return Err(BufferError::StaticDynamicConflict);
         }
+        if let Some(newest) = self.newest() {
+            if self.config.strict_ordering() && !is_static && timestamp < newest {
+                return Err(BufferError::TransformOlderThanLatest(
+                    timestamp.as_seconds_lossy(),
+                    newest.as_seconds_lossy(),
+                ));
+            }
+        }
 
         self.data.insert(timestamp, transform);
 
         if !self.is_static {
+            if let Some(previous) = self.latest_timestamp {
+                self.update_mean_interval(previous, timestamp);
+            }
             self.latest_timestamp = Some(match self.latest_timestamp {
                 Some(current_latest) if current_latest > timestamp => current_latest,
                 _ => timestamp,
@@ -318,6 +703,10 @@ where
 
     /// Retrieves a transform from the buffer at the specified timestamp.
     ///
+    /// Timestamps between stored samples are served according to the
+    /// buffer's [`InterpolationPolicy`], linear interpolation by default.
+    /// Dynamic results carry the requested timestamp.
+    ///
     /// # Errors
     ///
     /// Returns `BufferError::NoTransformAvailable` if the buffer holds no
@@ -332,6 +721,14 @@ where
     /// request is the only way a lookup on a non-empty dynamic buffer can
     /// fail to find data. Static buffers serve any requested timestamp.
     ///
+    /// With [`InterpolationPolicy::None`], returns
+    /// `BufferError::NoExactMatch` if no sample is stored at exactly the
+    /// requested timestamp.
+    ///
+    /// Returns `BufferError::InterpolationGapTooLarge` if the two samples
+    /// around the requested timestamp are further apart than the configured
+    /// [`BufferConfig::max_interpolation_gap`].
+    ///
     /// Returns `BufferError::TransformError` if interpolating between the two
     /// neighboring samples fails. With both frames pinned at insertion, this
     /// is only reachable through timestamp arithmetic: a span between the
@@ -383,29 +780,219 @@ where
         &self,
         timestamp: &T,
     ) -> Result<Transform<T>, BufferError> {
+        self.get_with_sources(timestamp).map(|(tf, _)| tf)
+    }
+
+    /// Retrieves a transform like [`Buffer::get`], together with the
+    /// timestamps of the stored samples it was computed from: the two
+    /// interpolated samples, or the same stamp twice when a single sample
+    /// was used.
+    pub(crate) fn get_with_sources(
+        &self,
+        timestamp: &T,
+    ) -> Result<(Transform<T>, (T, T)), BufferError> {
         if self.is_static {
-            match self.data.get(&T::static_timestamp()) {
-                Some(tf) => return Ok(tf.clone()),
-                None => return Err(BufferError::NoTransformAvailable),
-            }
+            let stamp = T::static_timestamp();
+            return match self.data.get(&stamp) {
+                Some(tf) => Ok((tf.clone(), (stamp, stamp))),
+                None => Err(BufferError::NoTransformAvailable),
+            };
         }
 
         let (before, after) = self.get_nearest(timestamp);
+        let policy = self.config.interpolation();
+        if let (Some(before), Some(after), Some(max_gap)) =
+            (before, after, self.config.max_interpolation_gap())
+        {
+            let too_far = after
+                .0
+                .duration_since(*before.0)
+                .ok()
+                .is_none_or(|gap| gap > max_gap);
+            if too_far && policy != InterpolationPolicy::None {
+                return Err(BufferError::InterpolationGapTooLarge(
+                    timestamp.as_seconds_lossy(),
+                    before.0.as_seconds_lossy(),
+                    after.0.as_seconds_lossy(),
+                ));
+            }
+        }
+        let held = |(stamp, tf): (&T, &Transform<T>)| {
+            let tf = Transform {
+                timestamp: *timestamp,
+                ..tf.clone()
+            };
+            (tf, (*stamp, *stamp))
+        };
 
-        match (before, after) {
-            (Some(before), Some(after)) => {
-                Ok(Transform::interpolate(before.1, after.1, *timestamp)?)
+        match (before, after, policy) {
+            (_, _, InterpolationPolicy::None) => self
+                .get_exact(timestamp)
+                .map(|tf| (tf, (*timestamp, *timestamp))),
+            (Some(before), Some(after), InterpolationPolicy::Nearest) => {
+                let closest = match (
+                    timestamp.duration_since(*before.0),
+                    after.0.duration_since(*timestamp),
+                ) {
+                    (Ok(to_before), Ok(to_after)) if to_after < to_before => after,
+                    _ => before,
+                };
+                Ok(held(closest))
             }
-            _ => match (self.data.first_key_value(), self.data.last_key_value()) {
-                (Some((first, _)), Some((last, _))) => Err(BufferError::TransformError(
-                    TransformError::TimestampOutOfRange(
-                        timestamp.as_seconds_lossy(),
-                        first.as_seconds_lossy(),
-                        last.as_seconds_lossy(),
-                    ),
-                )),
-                _ => Err(BufferError::NoTransformAvailable),
+            (Some(before), Some(_), InterpolationPolicy::Hold) => Ok(held(before)),
+            (Some(before), Some(after), _) => Ok((
+                Transform::interpolate(before.1, after.1, *timestamp)?,
+                (*before.0, *after.0),
+            )),
+            _ => Err(self.out_of_range(timestamp)),
+        }
+    }
+
+    /// Retrieves the stored transform closest in time to the given
+    /// timestamp, without interpolation.
+    ///
+    /// Use it when the data between samples is genuinely piecewise-constant.
+    /// The transform is returned as stored, with its own timestamp; of two
+    /// equally close samples, the earlier one wins. A static buffer returns
+    /// its transform for any timestamp.
+    ///
+    /// The timestamp must lie within the covered range, as for
+    /// [`Buffer::get`]: there is no extrapolation, not even within the
+    /// tolerance. With a `tolerance`, the closest sample must also lie within
+    /// `tolerance` of the timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BufferError::NoTransformAvailable` if the buffer holds no
+    /// transforms, `BufferError::TransformError` wrapping
+    /// `TransformError::TimestampOutOfRange` if the timestamp lies outside
+    /// the covered range, and
+    /// `BufferError::NoSampleWithinTolerance` if the closest sample is
+    /// further away than `tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     core::Buffer,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut buffer = Buffer::new();
+    /// for secs in [1, 3] {
+    ///     buffer
+    ///         .insert(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let closest = buffer
+    ///     .get_closest(&Timestamp::from_nanos(2_600_000_000), None)
+    ///     .unwrap();
+    /// assert_eq!(closest.translation.x, 3.0);
+    ///
+    /// let tolerance = Some(Duration::from_millis(100));
+    /// assert!(
+    ///     buffer
+    ///         .get_closest(&Timestamp::from_nanos(2_600_000_000), tolerance)
+    ///         .is_err()
+    /// );
+    /// ```
+    pub fn get_closest(
+        &self,
+        timestamp: &T,
+        tolerance: Option<Duration>,
+    ) -> Result<Transform<T>, BufferError> {
+        if self.is_static {
+            return self.get(timestamp);
+        }
+        let covered = self.data.keys().next().zip(self.data.keys().next_back());
+        if !covered.is_some_and(|(first, last)| (first..=last).contains(&timestamp)) {
+            return Err(self.out_of_range(timestamp));
+        }
+
+        // Only the neighbors on either side can be closest. An unrepresentable
+        // distance rules a candidate out.
+        let before = self
+            .data
+            .range(..=timestamp)
+            .next_back()
+            .and_then(|(&t, tf)| {
+                timestamp
+                    .duration_since(t)
+                    .ok()
+                    .map(|distance| (distance, tf))
+            });
+        let after = self.data.range(timestamp..).next().and_then(|(&t, tf)| {
+            t.duration_since(*timestamp)
+                .ok()
+                .map(|distance| (distance, tf))
+        });
+
+        let closest = match tolerance {
+            None => match (before, after) {
+                (Some(before), Some(after)) => {
+                    Some(if after.0 < before.0 { after } else { before })
+                }
+                _ => return Err(self.out_of_range(timestamp)),
             },
+            Some(tolerance) => [before, after]
+                .into_iter()
+                .flatten()
+                .filter(|&(distance, _)| distance <= tolerance)
+                .min_by_key(|&(distance, _)| distance),
+        };
+
+        match closest {
+            Some((_, tf)) => Ok(tf.clone()),
+            None if self.data.is_empty() => Err(BufferError::NoTransformAvailable),
+            None => Err(BufferError::NoSampleWithinTolerance(
+                timestamp.as_seconds_lossy(),
+            )),
+        }
+    }
+
+    /// Returns the stored transforms in timestamp order.
+    pub(crate) fn entries(&self) -> impl Iterator<Item = &Transform<T>> {
+        self.data.values()
+    }
+
+    /// Records the authority of the latest write, reusing the stored string
+    /// when it is unchanged.
+    pub(crate) fn set_authority(
+        &mut self,
+        authority: Option<&str>,
+    ) {
+        if self.authority.as_deref() != authority {
+            self.authority = authority.map(Into::into);
+        }
+    }
+
+    /// Renames `old` to `new` in the pinned frames and in every stored
+    /// transform. Frames other than `old` are left as they are.
+    pub(crate) fn rename_frame(
+        &mut self,
+        old: &str,
+        new: &str,
+    ) {
+        for frame in [&mut self.parent, &mut self.child].into_iter().flatten() {
+            if frame == old {
+                new.clone_into(frame);
+            }
+        }
+        for transform in self.data.values_mut() {
+            for frame in [&mut transform.parent, &mut transform.child] {
+                if frame == old {
+                    new.clone_into(frame);
+                }
+            }
         }
     }
 
@@ -423,6 +1010,83 @@ where
             return;
         }
         self.data.retain(|&k, _| k >= timestamp);
+        if self.data.is_empty() {
+            self.mean_interval = None;
+        }
+    }
+
+    /// Removes dynamic transforms newer than the given timestamp.
+    ///
+    /// This function deletes all transforms from the buffer that have a
+    /// timestamp greater than the given timestamp, for example to drop
+    /// "future" data when a log replay seeks backwards. The expiry reference
+    /// for `max_age` rewinds to the newest remaining transform, so samples
+    /// re-inserted after the seek are not expired against the deleted ones.
+    /// Static buffers are left untouched.
+    pub fn delete_after(
+        &mut self,
+        timestamp: T,
+    ) {
+        if self.is_static {
+            return;
+        }
+        self.data.retain(|&k, _| k <= timestamp);
+        self.rewind_latest();
+    }
+
+    /// Removes dynamic transforms within the inclusive range `start..=end`.
+    ///
+    /// Use it to cut out a corrupted or mis-stamped interval while keeping
+    /// the data on either side. Nothing is removed if `start` is greater
+    /// than `end`. As with [`Buffer::delete_after`], the expiry reference
+    /// for `max_age` moves to the newest remaining transform. Static buffers
+    /// are left untouched.
+    pub fn delete_between(
+        &mut self,
+        start: T,
+        end: T,
+    ) {
+        if self.is_static || start > end {
+            return;
+        }
+        self.data.retain(|&k, _| k < start || k > end);
+        self.rewind_latest();
+    }
+
+    /// Keeps only the transforms for which `keep` returns `true`, visiting
+    /// them in timestamp order.
+    ///
+    /// Unlike the `delete_*` methods this also applies to a static buffer's
+    /// transform. As with [`Buffer::delete_after`], the expiry reference for
+    /// `max_age` moves to the newest remaining transform.
+    pub fn retain<F>(
+        &mut self,
+        mut keep: F,
+    ) where
+        F: FnMut(&Transform<T>) -> bool,
+    {
+        self.data.retain(|_, tf| keep(tf));
+        if !self.is_static {
+            self.rewind_latest();
+        }
+    }
+
+    /// Describes why a timestamp cannot be served: the covered range, or an
+    /// empty buffer.
+    fn out_of_range(
+        &self,
+        timestamp: &T,
+    ) -> BufferError {
+        match (self.data.first_key_value(), self.data.last_key_value()) {
+            (Some((first, _)), Some((last, _))) => {
+                BufferError::TransformError(TransformError::TimestampOutOfRange(
+                    timestamp.as_seconds_lossy(),
+                    first.as_seconds_lossy(),
+                    last.as_seconds_lossy(),
+                ))
+            }
+            _ => BufferError::NoTransformAvailable,
+        }
     }
 
     /// Retrieves the nearest transforms before and after the given timestamp.
@@ -446,17 +1110,57 @@ where
         (before, after)
     }
 
-    /// Removes expired transforms from the buffer based on the `max_age`.
+    /// Moves the expiry reference to the newest remaining transform after a
+    /// deletion, and forgets the moving average if that rewinds it or
+    /// leaves the buffer empty.
+    fn rewind_latest(&mut self) {
+        let newest = self.data.last_key_value().map(|(&k, _)| k);
+        if newest.is_none() || newest < self.latest_timestamp {
+            self.mean_interval = None;
+        }
+        self.latest_timestamp = newest;
+    }
+
+    /// Folds the interval from `previous` to `timestamp` into the moving
+    /// average, if the insert advances the newest timestamp.
+    fn update_mean_interval(
+        &mut self,
+        previous: T,
+        timestamp: T,
+    ) {
+        if timestamp <= previous {
+            return;
+        }
+        let Ok(interval) = timestamp.duration_since(previous) else {
+            return;
+        };
+        let interval = interval.as_secs_f64();
+        self.mean_interval = Some(match self.mean_interval {
+            Some(mean) => mean + Self::RATE_SMOOTHING * (interval - mean),
+            None => interval,
+        });
+    }
+
+    /// Removes expired transforms from the buffer based on the configured
+    /// limits.
     ///
     /// This function deletes all transforms from the buffer that have a
-    /// timestamp older than `(latest inserted timestamp - max_age)`. Buffers
-    /// without a configured `max_age` never expire entries.
+    /// timestamp older than `(latest inserted timestamp - max_age)`, then
+    /// evicts the oldest transforms beyond `max_entries`. Buffers without a
+    /// configured limit never expire entries.
     fn delete_expired(&mut self) {
-        if let (Some(max_age), Some(latest_timestamp)) = (self.max_age, self.latest_timestamp) {
+        if let (Some(max_age), Some(latest_timestamp)) =
+            (self.config.max_age(), self.latest_timestamp)
+        {
             if let Ok(threshold) = latest_timestamp.checked_sub(max_age) {
                 self.data.retain(|&k, _| k >= threshold);
             }
         }
+        if let Some(max_entries) = self.config.max_entries() {
+            while self.data.len() > max_entries.get() {
+                self.data.pop_first();
+            }
+        }
     }
 }
 
@@ -1,12 +1,13 @@
 #[cfg(test)]
 mod buffer_tests {
     use crate::{
-        core::{Buffer, buffer::BufferError},
+        core::{Buffer, BufferConfig, InterpolationPolicy, buffer::BufferError},
         errors::TransformError,
         geometry::{Quaternion, Transform, Vector3},
         time::Timestamp,
     };
-    use core::time::Duration;
+    use alloc::vec::Vec;
+    use core::{num::NonZeroUsize, ops::Bound, time::Duration};
 
     fn create_transform(t: Timestamp) -> Transform {
         let translation = Vector3::new(1.0, 2.0, 3.0);
@@ -167,6 +168,315 @@ mod buffer_tests {
         let (before, after) = buffer.get_nearest(&Timestamp::from_nanos(1000));
         assert!(before.is_none());
         assert!(after.is_none());
+
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(buffer.oldest(), None);
+        assert_eq!(buffer.newest(), None);
+        assert!(matches!(
+            buffer.time_span(),
+            Err(BufferError::NoTransformAvailable)
+        ));
+    }
+
+    #[test]
+    fn size_and_span_accessors_report_coverage() {
+        let mut buffer = Buffer::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(1_500_000_000);
+        let t3 = Timestamp::from_nanos(4_000_000_000);
+
+        // Insertion order does not matter; the span runs oldest to newest.
+        for &t in &[t2, t3, t1] {
+            buffer.insert(create_transform(t)).unwrap();
+        }
+
+        assert_eq!(buffer.len(), 3);
+        assert!(!buffer.is_empty());
+        assert_eq!(buffer.oldest(), Some(t1));
+        assert_eq!(buffer.newest(), Some(t3));
+        assert_eq!(buffer.time_span().unwrap(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn get_exact_never_interpolates() {
+        let mut buffer = Buffer::new();
+        assert!(matches!(
+            buffer.get_exact(&Timestamp::from_nanos(1_000_000_000)),
+            Err(BufferError::NoTransformAvailable)
+        ));
+
+        let p1 = create_transform(Timestamp::from_nanos(1_000_000_000));
+        let p2 = create_transform(Timestamp::from_nanos(3_000_000_000));
+        buffer.insert(p1.clone()).unwrap();
+        buffer.insert(p2.clone()).unwrap();
+
+        assert_eq!(buffer.get_exact(&p1.timestamp).unwrap(), p1);
+        assert_eq!(buffer.get_exact(&p2.timestamp).unwrap(), p2);
+        assert!(matches!(
+            buffer.get_exact(&Timestamp::from_nanos(2_000_000_000)),
+            Err(BufferError::NoExactMatch(_))
+        ));
+        assert!(
+            buffer
+                .get_exact(&Timestamp::from_nanos(4_000_000_000))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn get_exact_on_static_buffer_matches_any_timestamp() {
+        let mut buffer = Buffer::new();
+        let static_tf = create_transform(Timestamp::zero());
+        buffer.insert(static_tf.clone()).unwrap();
+
+        assert_eq!(
+            buffer
+                .get_exact(&Timestamp::from_nanos(5_000_000_000))
+                .unwrap(),
+            static_tf
+        );
+    }
+
+    #[test]
+    fn get_closest_picks_the_nearer_neighbor() {
+        let mut buffer = Buffer::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+        buffer.insert(create_transform(t1)).unwrap();
+        buffer.insert(create_transform(t2)).unwrap();
+
+        let closest = |nanos| {
+            buffer
+                .get_closest(&Timestamp::from_nanos(nanos), None)
+                .map(|tf| tf.timestamp)
+        };
+        assert_eq!(closest(1_400_000_000).unwrap(), t1);
+        assert_eq!(closest(2_600_000_000).unwrap(), t2);
+        // Ties go to the earlier sample.
+        assert_eq!(closest(2_000_000_000).unwrap(), t1);
+        // Without a tolerance there is no extrapolation.
+        assert!(matches!(
+            closest(3_500_000_000),
+            Err(BufferError::TransformError(
+                TransformError::TimestampOutOfRange(..)
+            ))
+        ));
+    }
+
+    #[test]
+    fn get_closest_respects_the_tolerance() {
+        let mut buffer = Buffer::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+        buffer.insert(create_transform(t1)).unwrap();
+        buffer.insert(create_transform(t2)).unwrap();
+        let tolerance = Some(Duration::from_millis(200));
+
+        let closest = |nanos| {
+            buffer
+                .get_closest(&Timestamp::from_nanos(nanos), tolerance)
+                .map(|tf| tf.timestamp)
+        };
+        assert_eq!(closest(2_800_000_000).unwrap(), t2);
+        // Just past the newest sample, but within the tolerance: no
+        // extrapolation either way.
+        assert!(matches!(
+            closest(3_200_000_000),
+            Err(BufferError::TransformError(
+                TransformError::TimestampOutOfRange(..)
+            ))
+        ));
+        assert!(matches!(
+            closest(800_000_000),
+            Err(BufferError::TransformError(
+                TransformError::TimestampOutOfRange(..)
+            ))
+        ));
+        assert!(matches!(
+            closest(2_000_000_000),
+            Err(BufferError::NoSampleWithinTolerance(_))
+        ));
+        assert!(matches!(
+            Buffer::<Timestamp>::new().get_closest(&t1, tolerance),
+            Err(BufferError::NoTransformAvailable)
+        ));
+    }
+
+    fn policy_buffer(policy: InterpolationPolicy) -> Buffer {
+        let mut buffer = Buffer::with_config(BufferConfig::new().with_interpolation(policy));
+        for (nanos, x) in [(1_000_000_000, 1.0), (3_000_000_000, 3.0)] {
+            let mut tf = create_transform(Timestamp::from_nanos(nanos));
+            tf.translation = Vector3::new(x, 0.0, 0.0);
+            buffer.insert(tf).unwrap();
+        }
+        buffer
+    }
+
+    #[test]
+    fn interpolation_policy_selects_how_gaps_are_served() {
+        let query = Timestamp::from_nanos(2_500_000_000);
+        let x_at = |policy| {
+            policy_buffer(policy)
+                .get(&query)
+                .map(|tf| (tf.translation.x, tf.timestamp))
+        };
+
+        assert_eq!(x_at(InterpolationPolicy::Linear).unwrap(), (2.5, query));
+        assert_eq!(x_at(InterpolationPolicy::Nearest).unwrap(), (3.0, query));
+        assert_eq!(x_at(InterpolationPolicy::Hold).unwrap(), (1.0, query));
+        assert!(matches!(
+            x_at(InterpolationPolicy::None),
+            Err(BufferError::NoExactMatch(_))
+        ));
+    }
+
+    #[test]
+    fn interpolation_policies_never_extrapolate() {
+        let after_last = Timestamp::from_nanos(4_000_000_000);
+        for policy in [
+            InterpolationPolicy::Linear,
+            InterpolationPolicy::Nearest,
+            InterpolationPolicy::Hold,
+        ] {
+            assert!(matches!(
+                policy_buffer(policy).get(&after_last),
+                Err(BufferError::TransformError(
+                    TransformError::TimestampOutOfRange(..)
+                ))
+            ));
+        }
+
+        // Exact hits are served by every policy.
+        let last = Timestamp::from_nanos(3_000_000_000);
+        assert_eq!(
+            policy_buffer(InterpolationPolicy::Hold)
+                .get(&last)
+                .unwrap()
+                .translation,
+            Vector3::new(3.0, 0.0, 0.0)
+        );
+        assert!(policy_buffer(InterpolationPolicy::None).get(&last).is_ok());
+        assert_eq!(
+            BufferConfig::default().interpolation(),
+            InterpolationPolicy::Linear
+        );
+    }
+
+    #[test]
+    fn get_latest_and_get_oldest_return_stored_transforms() {
+        let mut buffer = Buffer::new();
+        assert!(buffer.get_latest().is_none());
+        assert!(buffer.get_oldest().is_none());
+
+        let p1 = create_transform(Timestamp::from_nanos(1_000_000_000));
+        let p2 = create_transform(Timestamp::from_nanos(2_000_000_000));
+        buffer.insert(p2.clone()).unwrap();
+        buffer.insert(p1.clone()).unwrap();
+
+        assert_eq!(buffer.get_latest(), Some(&p2));
+        assert_eq!(buffer.get_oldest(), Some(&p1));
+    }
+
+    #[test]
+    fn get_bounds_returns_the_bracketing_samples() {
+        let mut buffer = Buffer::new();
+        let t = |secs: u128| Timestamp::from_nanos(secs * 1_000_000_000);
+        assert_eq!(buffer.get_bounds(&t(1)), (None, None));
+
+        let p1 = create_transform(t(1));
+        let p3 = create_transform(t(3));
+        buffer.insert(p1.clone()).unwrap();
+        buffer.insert(p3.clone()).unwrap();
+
+        assert_eq!(buffer.get_bounds(&t(2)), (Some(&p1), Some(&p3)));
+        assert_eq!(buffer.get_bounds(&t(3)), (Some(&p3), Some(&p3)));
+        assert_eq!(buffer.get_bounds(&t(0)), (None, Some(&p1)));
+        assert_eq!(buffer.get_bounds(&t(4)), (Some(&p3), None));
+
+        let mut static_buffer = Buffer::new();
+        let fixed = create_transform(Timestamp::zero());
+        static_buffer.insert(fixed.clone()).unwrap();
+        assert_eq!(
+            static_buffer.get_bounds(&t(5)),
+            (Some(&fixed), Some(&fixed))
+        );
+    }
+
+    #[test]
+    fn range_yields_stored_transforms_within_bounds() {
+        let mut buffer = Buffer::new();
+        let timestamps = [1, 2, 3, 4].map(|s| Timestamp::from_nanos(s * 1_000_000_000));
+        for &t in &timestamps {
+            buffer.insert(create_transform(t)).unwrap();
+        }
+        let stamps = |buffer: &Buffer, range: (Bound<Timestamp>, Bound<Timestamp>)| {
+            buffer
+                .range(range)
+                .map(|tf| tf.timestamp)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            stamps(
+                &buffer,
+                (
+                    Bound::Included(timestamps[1]),
+                    Bound::Excluded(timestamps[3])
+                )
+            ),
+            timestamps[1..3]
+        );
+        assert_eq!(
+            stamps(&buffer, (Bound::Excluded(timestamps[1]), Bound::Unbounded)),
+            timestamps[2..]
+        );
+        assert_eq!(
+            buffer.range(..).next_back().unwrap().timestamp,
+            timestamps[3]
+        );
+
+        // Inverted and empty exclusive ranges yield nothing instead of
+        // panicking.
+        assert!(
+            stamps(
+                &buffer,
+                (
+                    Bound::Included(timestamps[2]),
+                    Bound::Included(timestamps[1])
+                )
+            )
+            .is_empty()
+        );
+        assert!(
+            stamps(
+                &buffer,
+                (
+                    Bound::Excluded(timestamps[2]),
+                    Bound::Excluded(timestamps[2])
+                )
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            stamps(
+                &buffer,
+                (
+                    Bound::Included(timestamps[2]),
+                    Bound::Included(timestamps[2])
+                )
+            ),
+            [timestamps[2]]
+        );
+    }
+
+    #[test]
+    fn static_buffer_has_zero_span() {
+        let mut buffer = Buffer::new();
+        buffer.insert(create_transform(Timestamp::zero())).unwrap();
+
+        assert_eq!(buffer.oldest(), Some(Timestamp::zero()));
+        assert_eq!(buffer.newest(), Some(Timestamp::zero()));
+        assert_eq!(buffer.time_span().unwrap(), Duration::ZERO);
     }
 
     #[test]
@@ -189,6 +499,98 @@ mod buffer_tests {
         assert!(buffer.get(&p2.timestamp).is_ok());
     }
 
+    #[test]
+    fn delete_after() {
+        let mut buffer = Buffer::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+
+        let p1 = create_transform(t);
+        let p2 = create_transform((t + Duration::from_secs(2)).unwrap());
+
+        buffer.insert(p1.clone()).unwrap();
+        buffer.insert(p2.clone()).unwrap();
+
+        buffer.delete_after(Timestamp::from_nanos(2_000_000_000));
+
+        assert!(buffer.get(&p1.timestamp).is_ok());
+        assert!(buffer.get(&p2.timestamp).is_err());
+    }
+
+    #[test]
+    fn delete_between_is_inclusive() {
+        let mut buffer = Buffer::new();
+        let timestamps = [1, 2, 3, 4].map(|s| Timestamp::from_nanos(s * 1_000_000_000));
+        for &t in &timestamps {
+            buffer.insert(create_transform(t)).unwrap();
+        }
+
+        // Both bounds are removed; a lookup between remaining samples would
+        // interpolate over the gap, so cut out the leading interval.
+        buffer.delete_between(timestamps[0], timestamps[1]);
+
+        assert!(buffer.get(&timestamps[0]).is_err());
+        assert!(buffer.get(&timestamps[1]).is_err());
+        assert!(buffer.get(&timestamps[2]).is_ok());
+        assert!(buffer.get(&timestamps[3]).is_ok());
+    }
+
+    #[test]
+    fn delete_between_ignores_reversed_range() {
+        let mut buffer = Buffer::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+        buffer.insert(create_transform(t1)).unwrap();
+        buffer.insert(create_transform(t2)).unwrap();
+
+        buffer.delete_between(t2, t1);
+
+        assert!(buffer.get(&t1).is_ok());
+        assert!(buffer.get(&t2).is_ok());
+    }
+
+    #[test]
+    fn delete_after_rewinds_the_expiry_reference() {
+        let mut buffer = Buffer::with_max_age(Duration::from_secs(5));
+        let t1 = Timestamp::from_nanos(10_000_000_000);
+        let t2 = Timestamp::from_nanos(14_000_000_000);
+        let t3 = Timestamp::from_nanos(8_000_000_000);
+
+        buffer.insert(create_transform(t1)).unwrap();
+        buffer.insert(create_transform(t2)).unwrap();
+        buffer.delete_after(t1);
+
+        // Seeking back to t1 must not leave t2 as the latest timestamp, or
+        // the re-inserted sample at t3 would expire on arrival.
+        buffer.insert(create_transform(t3)).unwrap();
+        assert!(buffer.get(&t1).is_ok());
+        assert!(buffer.get(&t3).is_ok());
+    }
+
+    #[test]
+    fn retain_keeps_matching_transforms_and_rewinds_the_expiry_reference() {
+        let mut buffer = Buffer::with_max_age(Duration::from_secs(5));
+        let timestamps = [10, 12, 14].map(|s| Timestamp::from_nanos(s * 1_000_000_000));
+        for &t in &timestamps {
+            buffer.insert(create_transform(t)).unwrap();
+        }
+
+        buffer.retain(|tf| tf.timestamp != timestamps[2]);
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.newest(), Some(timestamps[1]));
+
+        // The dropped newest sample no longer anchors the expiry.
+        let t = Timestamp::from_nanos(8_000_000_000);
+        buffer.insert(create_transform(t)).unwrap();
+        assert!(buffer.get(&t).is_ok());
+
+        let mut static_buffer = Buffer::new();
+        static_buffer
+            .insert(create_transform(Timestamp::zero()))
+            .unwrap();
+        static_buffer.retain(|_| false);
+        assert!(static_buffer.is_empty());
+    }
+
     #[test]
     fn delete_expired() {
         let mut buffer = Buffer::with_max_age(Duration::from_secs(10));
@@ -320,6 +722,13 @@ mod buffer_tests {
             static_tf,
             "static transforms must survive manual cleanup"
         );
+
+        buffer.delete_after(Timestamp::from_nanos(5_000_000_000));
+        buffer.delete_between(Timestamp::zero(), Timestamp::from_nanos(5_000_000_000));
+        assert_eq!(
+            buffer.get(&Timestamp::from_nanos(9_000_000_000)).unwrap(),
+            static_tf,
+        );
     }
 
     #[test]
@@ -444,4 +853,239 @@ mod buffer_tests {
         );
         assert!(buffer.get(&t_new).is_ok());
     }
+
+    #[test]
+    fn insert_evicts_oldest_entries_beyond_max_entries() {
+        let config = BufferConfig::new().with_max_entries(NonZeroUsize::new(2).unwrap());
+        let mut buffer = Buffer::with_config(config);
+
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+        let t3 = Timestamp::from_nanos(3_000_000_000);
+
+        buffer.insert(create_transform(t1)).unwrap();
+        buffer.insert(create_transform(t2)).unwrap();
+        buffer.insert(create_transform(t3)).unwrap();
+
+        assert!(
+            buffer.get(&t1).is_err(),
+            "the oldest entry must be evicted once max_entries is exceeded"
+        );
+        assert!(buffer.get(&t2).is_ok());
+        assert!(buffer.get(&t3).is_ok());
+    }
+
+    #[test]
+    fn max_entries_and_max_age_apply_together() {
+        let config = BufferConfig::new()
+            .with_max_age(Duration::from_secs(2))
+            .with_max_entries(NonZeroUsize::new(3).unwrap());
+        let mut buffer = Buffer::with_config(config);
+
+        // A burst within max_age: only the entry limit trims it.
+        for millis in [1_000, 1_100, 1_200, 1_300] {
+            buffer
+                .insert(create_transform(Timestamp::from_nanos(millis * 1_000_000)))
+                .unwrap();
+        }
+        assert!(buffer.get(&Timestamp::from_nanos(1_000_000_000)).is_err());
+        assert!(buffer.get(&Timestamp::from_nanos(1_100_000_000)).is_ok());
+
+        // A sample far ahead: the age limit removes the whole burst.
+        let t_late = Timestamp::from_nanos(10_000_000_000);
+        buffer.insert(create_transform(t_late)).unwrap();
+        assert!(buffer.get(&Timestamp::from_nanos(1_300_000_000)).is_err());
+        assert!(buffer.get(&t_late).is_ok());
+    }
+
+    #[test]
+    fn max_entries_never_trims_static_buffers() {
+        let config = BufferConfig::new().with_max_entries(NonZeroUsize::new(1).unwrap());
+        let mut buffer = Buffer::with_config(config);
+
+        let static_tf = create_transform(Timestamp::zero());
+        buffer.insert(static_tf.clone()).unwrap();
+        buffer.insert(static_tf.clone()).unwrap();
+
+        assert_eq!(
+            buffer.get(&Timestamp::from_nanos(1_000_000_000)).unwrap(),
+            static_tf
+        );
+        assert_eq!(buffer.config(), config);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn strict_ordering_rejects_inserts_older_than_the_newest_sample() {
+        let config = BufferConfig::new().with_strict_ordering(true);
+        let mut buffer = Buffer::with_config(config);
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+
+        buffer.insert(create_transform(t2)).unwrap();
+        let result = buffer.insert(create_transform(t1));
+        assert!(
+            matches!(
+                result,
+                Err(BufferError::TransformOlderThanLatest(stamp, newest))
+                    if stamp == 1.0 && newest == 2.0
+            ),
+            "expected TransformOlderThanLatest, got {result:?}"
+        );
+        assert_eq!(buffer.len(), 1);
+
+        // The newest stamp may be replaced, and newer samples are accepted.
+        buffer.insert(create_transform(t2)).unwrap();
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(3_000_000_000)))
+            .unwrap();
+        assert_eq!(buffer.len(), 2);
+    }
+
+    #[test]
+    fn out_of_order_inserts_are_stored_without_strict_ordering() {
+        let mut buffer = Buffer::new();
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(2_000_000_000)))
+            .unwrap();
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(1_000_000_000)))
+            .unwrap();
+
+        assert_eq!(buffer.len(), 2);
+        assert!(!buffer.config().strict_ordering());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn max_interpolation_gap_rejects_bridging_wide_gaps() {
+        let seconds = |secs: u128| Timestamp::from_nanos(secs * 1_000_000_000);
+        for policy in [
+            InterpolationPolicy::Linear,
+            InterpolationPolicy::Nearest,
+            InterpolationPolicy::Hold,
+        ] {
+            let config = BufferConfig::new()
+                .with_interpolation(policy)
+                .with_max_interpolation_gap(Duration::from_secs(1));
+            let mut buffer = Buffer::with_config(config);
+            for secs in [1, 2, 5] {
+                buffer.insert(create_transform(seconds(secs))).unwrap();
+            }
+
+            // Within the limit, and exactly at stored samples, lookups work.
+            assert!(buffer.get(&Timestamp::from_nanos(1_500_000_000)).is_ok());
+            assert!(buffer.get(&seconds(2)).is_ok());
+            assert!(buffer.get(&seconds(5)).is_ok());
+
+            let result = buffer.get(&seconds(3));
+            assert!(
+                matches!(
+                    result,
+                    Err(BufferError::InterpolationGapTooLarge(t, before, after))
+                        if t == 3.0 && before == 2.0 && after == 5.0
+                ),
+                "expected InterpolationGapTooLarge for {policy:?}, got {result:?}"
+            );
+        }
+
+        // Exact-match buffers keep reporting the missing sample.
+        let config = BufferConfig::new()
+            .with_interpolation(InterpolationPolicy::None)
+            .with_max_interpolation_gap(Duration::from_secs(1));
+        let mut buffer = Buffer::with_config(config);
+        for secs in [2, 5] {
+            buffer.insert(create_transform(seconds(secs))).unwrap();
+        }
+        assert!(matches!(
+            buffer.get(&seconds(3)),
+            Err(BufferError::NoExactMatch(_))
+        ));
+        assert_eq!(
+            buffer.config().max_interpolation_gap(),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(BufferConfig::new().max_interpolation_gap(), None);
+    }
+
+    #[test]
+    fn mean_interval_follows_stamps_of_in_order_inserts() {
+        let mut buffer = Buffer::new();
+        assert_eq!(buffer.mean_interval(), None);
+        assert_eq!(buffer.rate(), None);
+
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(1_000_000_000)))
+            .unwrap();
+        assert_eq!(buffer.mean_interval(), None);
+
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(1_500_000_000)))
+            .unwrap();
+        assert_eq!(buffer.mean_interval(), Some(Duration::from_millis(500)));
+
+        // An out-of-order sample does not advance the newest stamp and is
+        // left out of the average.
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(1_200_000_000)))
+            .unwrap();
+        assert_eq!(buffer.mean_interval(), Some(Duration::from_millis(500)));
+
+        // A 1.5 s gap moves the average by a tenth of the difference.
+        buffer
+            .insert(create_transform(Timestamp::from_nanos(3_000_000_000)))
+            .unwrap();
+        let mean = buffer.mean_interval().unwrap().as_secs_f64();
+        assert!((mean - 0.6).abs() < 1e-9, "unexpected mean {mean}");
+        assert!((buffer.rate().unwrap() - 1.0 / 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_interval_resets_when_deletions_rewind_the_buffer() {
+        let filled = || {
+            let mut buffer = Buffer::new();
+            for millis in [1_000, 1_500, 2_000] {
+                buffer
+                    .insert(create_transform(Timestamp::from_millis(millis)))
+                    .unwrap();
+            }
+            assert_eq!(buffer.mean_interval(), Some(Duration::from_millis(500)));
+            buffer
+        };
+
+        let mut buffer = filled();
+        buffer.delete_after(Timestamp::from_millis(1_500));
+        assert_eq!(buffer.mean_interval(), None);
+        // The average restarts from the rewound newest sample.
+        buffer
+            .insert(create_transform(Timestamp::from_millis(1_600)))
+            .unwrap();
+        assert_eq!(buffer.mean_interval(), Some(Duration::from_millis(100)));
+
+        let mut buffer = filled();
+        buffer.delete_between(Timestamp::from_millis(1_800), Timestamp::from_millis(2_000));
+        assert_eq!(buffer.mean_interval(), None);
+
+        // Deletions that keep the newest sample keep the average.
+        let mut buffer = filled();
+        buffer.delete_between(Timestamp::from_millis(1_200), Timestamp::from_millis(1_500));
+        assert_eq!(buffer.mean_interval(), Some(Duration::from_millis(500)));
+
+        let mut buffer = filled();
+        buffer.delete_before(Timestamp::from_millis(3_000));
+        assert_eq!(buffer.mean_interval(), None);
+
+        let mut buffer = filled();
+        buffer.retain(|tf| tf.timestamp < Timestamp::from_millis(2_000));
+        assert_eq!(buffer.mean_interval(), None);
+    }
+
+    #[test]
+    fn static_buffers_have_no_rate() {
+        let mut buffer = Buffer::new();
+        buffer.insert(create_transform(Timestamp::zero())).unwrap();
+
+        assert_eq!(buffer.mean_interval(), None);
+        assert_eq!(buffer.rate(), None);
+    }
 }
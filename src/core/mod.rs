@@ -1,7 +1,13 @@
 //! Core storage and lookup: the transform Registry and its per-frame Buffers.
 
 pub mod buffer;
+pub mod namespace;
 pub mod registry;
 
-pub use buffer::Buffer;
-pub use registry::Registry;
+pub use buffer::{Buffer, BufferConfig, InterpolationPolicy};
+pub use namespace::Namespace;
+pub use registry::{
+    Diagnostics, EdgeDiagnostics, EdgeDiff, ExplainedEdge, FrameStats, LookupCache,
+    LookupExplanation, Registry, RegistryBuilder, RegistryGroup, RegistryStats, Snapshot,
+    TreeIssue,
+};
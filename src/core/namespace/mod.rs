@@ -0,0 +1,176 @@
+//! Frame-name prefixes for merging several robots' trees into one registry.
+//!
+//! A [`Namespace`] maps a robot-local frame such as `base` to `robot1/base`,
+//! like tf's `tf_prefix`. Frames registered as global, typically a shared
+//! `map` or `world`, are left unprefixed so the robots' trees join there.
+//!
+//! # Examples
+//!
+//! ```
+//! use transforms::{
+//!     Registry,
+//!     core::Namespace,
+//!     geometry::{Quaternion, Transform, Vector3},
+//!     time::Timestamp,
+//! };
+//!
+//! let robot1 = Namespace::new("robot1").with_global_frame("map");
+//! let mut transforms = [
+//!     Transform {
+//!         translation: Vector3::new(1.0, 0.0, 0.0),
+//!         rotation: Quaternion::identity(),
+//!         timestamp: Timestamp::zero(),
+//!         parent: "map".into(),
+//!         child: "base".into(),
+//!     },
+//!     Transform {
+//!         translation: Vector3::new(0.0, 0.0, 0.5),
+//!         rotation: Quaternion::identity(),
+//!         timestamp: Timestamp::zero(),
+//!         parent: "base".into(),
+//!         child: "lidar".into(),
+//!     },
+//! ];
+//! robot1.apply_all(&mut transforms);
+//!
+//! let mut registry = Registry::new();
+//! for transform in transforms {
+//!     registry.add_transform(transform).unwrap();
+//! }
+//!
+//! let lidar = registry
+//!     .get_transform("map", &robot1.frame("lidar"), Timestamp::zero())
+//!     .unwrap();
+//! assert_eq!(lidar.child, "robot1/lidar");
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{geometry::Transform, time::TimePoint};
+
+/// A frame-name prefix, separated from the local name by `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Namespace {
+    prefix: String,
+    global_frames: Vec<String>,
+}
+
+impl Namespace {
+    /// Creates a namespace with the given prefix.
+    ///
+    /// Leading and trailing `/` are ignored, so `"robot1"` and `"/robot1/"`
+    /// name the same namespace. An empty prefix leaves every frame name
+    /// unchanged.
+    #[must_use]
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.trim_matches('/').into(),
+            global_frames: Vec::new(),
+        }
+    }
+
+    /// Returns the namespace with `frame` exempt from prefixing, for frames
+    /// shared by every namespace such as a common `map`.
+    #[must_use]
+    pub fn with_global_frame(
+        mut self,
+        frame: &str,
+    ) -> Self {
+        if !self.is_global(frame) {
+            self.global_frames.push(frame.into());
+        }
+        self
+    }
+
+    /// Returns the prefix, without separators.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns `true` if `frame` is exempt from prefixing.
+    #[must_use]
+    pub fn is_global(
+        &self,
+        frame: &str,
+    ) -> bool {
+        self.global_frames.iter().any(|global| global == frame)
+    }
+
+    /// Returns the namespaced name of a local frame.
+    ///
+    /// Global frames are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::core::Namespace;
+    ///
+    /// let robot1 = Namespace::new("robot1").with_global_frame("map");
+    /// assert_eq!(robot1.frame("base"), "robot1/base");
+    /// assert_eq!(robot1.frame("map"), "map");
+    /// ```
+    #[must_use]
+    pub fn frame(
+        &self,
+        local: &str,
+    ) -> String {
+        if self.prefix.is_empty() || self.is_global(local) {
+            local.into()
+        } else {
+            format!("{}/{local}", self.prefix)
+        }
+    }
+
+    /// Returns the local name of a frame in this namespace, or `None` if the
+    /// frame belongs to another namespace. Global frames are returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::core::Namespace;
+    ///
+    /// let robot1 = Namespace::new("robot1");
+    /// assert_eq!(robot1.strip("robot1/base"), Some("base"));
+    /// assert_eq!(robot1.strip("robot2/base"), None);
+    /// ```
+    #[must_use]
+    pub fn strip<'a>(
+        &self,
+        frame: &'a str,
+    ) -> Option<&'a str> {
+        if self.prefix.is_empty() || self.is_global(frame) {
+            return Some(frame);
+        }
+        frame
+            .strip_prefix(self.prefix.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+    }
+
+    /// Prefixes the parent and child frames of a transform.
+    pub fn apply<T>(
+        &self,
+        transform: &mut Transform<T>,
+    ) where
+        T: TimePoint,
+    {
+        transform.parent = self.frame(&transform.parent);
+        transform.child = self.frame(&transform.child);
+    }
+
+    /// Prefixes the frames of every transform in a batch.
+    pub fn apply_all<T>(
+        &self,
+        transforms: &mut [Transform<T>],
+    ) where
+        T: TimePoint,
+    {
+        for transform in transforms {
+            self.apply(transform);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod namespace_tests {
+    use crate::{
+        Registry,
+        core::Namespace,
+        geometry::{Quaternion, Transform, Vector3},
+        time::Timestamp,
+    };
+
+    fn transform(
+        parent: &str,
+        child: &str,
+        x: f64,
+    ) -> Transform {
+        Transform {
+            translation: Vector3::new(x, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::zero(),
+            parent: parent.into(),
+            child: child.into(),
+        }
+    }
+
+    #[test]
+    fn frame_and_strip_are_inverse() {
+        let namespace = Namespace::new("/robot1/").with_global_frame("map");
+
+        assert_eq!(namespace.prefix(), "robot1");
+        for local in ["base", "arm/wrist", "map"] {
+            let frame = namespace.frame(local);
+            assert_eq!(namespace.strip(&frame), Some(local));
+        }
+        assert_eq!(namespace.strip("robot10/base"), None);
+        assert_eq!(namespace.strip("robot1"), None);
+    }
+
+    #[test]
+    fn empty_prefix_leaves_names_unchanged() {
+        let namespace = Namespace::new("");
+
+        assert_eq!(namespace.frame("base"), "base");
+        assert_eq!(namespace.strip("robot1/base"), Some("robot1/base"));
+    }
+
+    #[test]
+    fn namespaced_trees_merge_at_global_frames() {
+        let mut registry = Registry::new();
+        for (name, x) in [("robot1", 1.0), ("robot2", -1.0)] {
+            let namespace = Namespace::new(name).with_global_frame("map");
+            let mut batch = [transform("map", "base", x), transform("base", "lidar", 0.5)];
+            namespace.apply_all(&mut batch);
+            for transform in batch {
+                registry.add_transform(transform).unwrap();
+            }
+        }
+
+        let robot1 = Namespace::new("robot1");
+        let robot2 = Namespace::new("robot2");
+        let result = registry
+            .get_transform(
+                &robot1.frame("lidar"),
+                &robot2.frame("lidar"),
+                Timestamp::zero(),
+            )
+            .unwrap();
+
+        assert_eq!(result.translation, Vector3::new(-2.0, 0.0, 0.0));
+        assert_eq!(result.parent, "robot1/lidar");
+        assert_eq!(result.child, "robot2/lidar");
+    }
+}
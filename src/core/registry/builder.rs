@@ -0,0 +1,187 @@
+use alloc::{string::String, vec::Vec};
+use core::{marker::PhantomData, num::NonZeroUsize, time::Duration};
+use hashbrown::HashMap;
+
+use crate::{
+    core::BufferConfig,
+    time::{TimePoint, Timestamp},
+};
+
+use super::Registry;
+
+/// A builder for a [`Registry`] with per-child-frame retention settings.
+///
+/// Every buffer starts from the default [`BufferConfig`]. A per-frame
+/// maximum age replaces only the default's `max_age` for that child frame;
+/// all other settings still come from the default. Overrides are resolved
+/// when the frame's buffer is created, so they apply whether they are set
+/// before or after the defaults. Among the default settings, later calls
+/// win: [`RegistryBuilder::default_config`] replaces an earlier
+/// `default_max_age` or `default_max_entries`.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use transforms::{Registry, time::Timestamp};
+///
+/// let registry = Registry::<Timestamp>::builder()
+///     .default_max_age(Duration::from_secs(2))
+///     .frame_max_age("odom", Duration::from_secs(60))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegistryBuilder<T = Timestamp>
+where
+    T: TimePoint,
+{
+    config: BufferConfig,
+    frame_max_age: HashMap<String, Duration>,
+    time_jump_threshold: Option<Duration>,
+    remaps: Vec<(String, String)>,
+    _time: PhantomData<T>,
+}
+
+impl<T> RegistryBuilder<T>
+where
+    T: TimePoint,
+{
+    /// Creates a builder without any retention limit, equivalent to
+    /// [`Registry::new`] when built as-is.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: BufferConfig::new(),
+            frame_max_age: HashMap::new(),
+            time_jump_threshold: None,
+            remaps: Vec::new(),
+            _time: PhantomData,
+        }
+    }
+
+    /// Sets the default retention settings for every child frame.
+    ///
+    /// This replaces earlier calls to [`RegistryBuilder::default_max_age`]
+    /// and [`RegistryBuilder::default_max_entries`]; per-frame overrides
+    /// are kept.
+    #[must_use]
+    pub fn default_config(
+        mut self,
+        config: BufferConfig,
+    ) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the default maximum age of dynamic transforms, relative to the
+    /// latest inserted timestamp of their child frame.
+    #[must_use]
+    pub fn default_max_age(
+        mut self,
+        max_age: Duration,
+    ) -> Self {
+        self.config = self.config.with_max_age(max_age);
+        self
+    }
+
+    /// Sets the default maximum number of transforms kept per child frame.
+    #[must_use]
+    pub fn default_max_entries(
+        mut self,
+        max_entries: NonZeroUsize,
+    ) -> Self {
+        self.config = self.config.with_max_entries(max_entries);
+        self
+    }
+
+    /// Overrides the maximum age for the buffer of one child frame.
+    ///
+    /// Calling it again for the same frame replaces the earlier override.
+    #[must_use]
+    pub fn frame_max_age(
+        mut self,
+        child: &str,
+        max_age: Duration,
+    ) -> Self {
+        self.frame_max_age.insert(child.into(), max_age);
+        self
+    }
+
+    /// Clears all dynamic buffers when time jumps backwards by more than
+    /// `threshold`.
+    ///
+    /// A dynamic transform stamped more than `threshold` before the newest
+    /// dynamic transform in the registry, such as after a simulation reset
+    /// or when a recording loops, discards every dynamic buffer and is then
+    /// inserted into an empty dynamic tree. Static transforms are kept. If
+    /// the insert is rejected, nothing is discarded. Without this option,
+    /// such a transform is stored alongside the newer history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::builder()
+    ///     .clear_on_time_jump(Duration::from_secs(1))
+    ///     .build();
+    /// let transform = |seconds: u64| Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp: Timestamp::from_millis(seconds * 1000),
+    ///     parent: "map".into(),
+    ///     child: "base".into(),
+    /// };
+    ///
+    /// registry.add_transform(transform(10)).unwrap();
+    /// // The simulation restarts: the old history is dropped.
+    /// registry.add_transform(transform(2)).unwrap();
+    /// assert_eq!(registry.buffer("base").unwrap().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn clear_on_time_jump(
+        mut self,
+        threshold: Duration,
+    ) -> Self {
+        self.time_jump_threshold = Some(threshold);
+        self
+    }
+
+    /// Maps a legacy frame name to its current name, as
+    /// [`Registry::remap_frame`] does. Remaps are applied in call order.
+    #[must_use]
+    pub fn remap_frame(
+        mut self,
+        old: &str,
+        new: &str,
+    ) -> Self {
+        self.remaps.push((old.into(), new.into()));
+        self
+    }
+
+    /// Builds the registry.
+    #[must_use]
+    pub fn build(self) -> Registry<T> {
+        let mut registry = Registry::with_config(self.config);
+        registry.frame_max_age = self.frame_max_age;
+        registry.time_jump_threshold = self.time_jump_threshold;
+        for (old, new) in &self.remaps {
+            registry.remap_frame(old, new);
+        }
+        registry
+    }
+}
+
+impl<T> Default for RegistryBuilder<T>
+where
+    T: TimePoint,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
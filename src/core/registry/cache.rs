@@ -0,0 +1,118 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    Registry,
+    errors::TransformError,
+    geometry::Transform,
+    time::{TimePoint, Timestamp},
+};
+
+/// Memoizes [`Registry::get_transform`] results for one processing cycle.
+///
+/// Pipelines often look up the same `(from, to, timestamp)` several times
+/// per cycle, for example when several consumers share a sensor stamp. The
+/// cache answers repeats without walking and composing the chain again.
+///
+/// The cache borrows the registry, so the registry cannot change while the
+/// cache lives and a cached result is never stale. Create one per cycle and
+/// drop it before inserting new transforms. Only successful lookups are
+/// cached; the cache is scanned linearly, as a cycle asks few distinct
+/// questions.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Registry,
+///     core::LookupCache,
+///     geometry::{Quaternion, Transform, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let mut registry = Registry::<Timestamp>::new();
+/// registry
+///     .add_transform(Transform {
+///         translation: Vector3::new(1.0, 0.0, 0.0),
+///         rotation: Quaternion::identity(),
+///         timestamp: Timestamp::zero(),
+///         parent: "base".into(),
+///         child: "lidar".into(),
+///     })
+///     .unwrap();
+///
+/// let stamp = Timestamp::from_nanos(5);
+/// let mut cache = LookupCache::new(&registry);
+/// let first = cache.get_transform("base", "lidar", stamp).unwrap();
+/// let second = cache.get_transform("base", "lidar", stamp).unwrap();
+/// assert_eq!(first, second);
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct LookupCache<'a, T = Timestamp>
+where
+    T: TimePoint,
+{
+    registry: &'a Registry<T>,
+    entries: Vec<(String, String, T, Transform<T>)>,
+}
+
+impl<'a, T> LookupCache<'a, T>
+where
+    T: TimePoint,
+{
+    /// Creates an empty cache over `registry`.
+    #[must_use]
+    pub const fn new(registry: &'a Registry<T>) -> Self {
+        Self {
+            registry,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the registry the cache answers from.
+    #[must_use]
+    pub const fn registry(&self) -> &'a Registry<T> {
+        self.registry
+    }
+
+    /// Returns the number of cached results.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no result is cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Looks up a transform like [`Registry::get_transform`], answering
+    /// repeated questions from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Registry::get_transform`]. Failed lookups are
+    /// not cached.
+    pub fn get_transform(
+        &mut self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+    ) -> Result<Transform<T>, TransformError> {
+        let cached = self
+            .entries
+            .iter()
+            .find(|(cached_from, cached_to, stamp, _)| {
+                cached_from == from && cached_to == to && *stamp == timestamp
+            });
+        if let Some((.., tf)) = cached {
+            return Ok(tf.clone());
+        }
+
+        let tf = self.registry.get_transform(from, to, timestamp)?;
+        self.entries
+            .push((from.into(), to.into(), timestamp, tf.clone()));
+        Ok(tf)
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt, time::Duration};
+
+use crate::{
+    core::Buffer,
+    time::{TimePoint, Timestamp},
+};
+
+/// A health report of every edge in a [`Registry`](super::Registry),
+/// returned by [`Registry::diagnostics`](super::Registry::diagnostics).
+///
+/// The report prints one line per edge through its
+/// [`Display`](fmt::Display) impl and, with the `serde` feature, serializes
+/// for export.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub struct Diagnostics<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The time the report was taken at, as passed by the caller.
+    pub now: T,
+    /// Per-edge diagnostics, sorted by child frame.
+    pub edges: Vec<EdgeDiagnostics>,
+}
+
+/// The diagnostics of a single parent-child edge.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub struct EdgeDiagnostics {
+    /// The parent frame of the edge.
+    pub parent: String,
+    /// The child frame owning the edge.
+    pub child: String,
+    /// `true` if the edge is static.
+    pub is_static: bool,
+    /// The smoothed update rate in hertz, see [`Buffer::rate`].
+    pub rate: Option<f64>,
+    /// How far the newest transform lies behind the report's `now`. `None`
+    /// for static edges and for edges stamped after `now`.
+    pub delay: Option<Duration>,
+    /// The time covered by the stored transforms, see
+    /// [`Buffer::time_span`].
+    pub span: Option<Duration>,
+    /// The authority that wrote the newest insert, if it was given one.
+    pub authority: Option<String>,
+}
+
+/// A problem in the structure of a [`Registry`](super::Registry), reported
+/// by [`Registry::validate`](super::Registry::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TreeIssue {
+    /// The frames form more than one tree, listed by their roots in name
+    /// order. Lookups across trees fail with `TransformError::Disconnected`.
+    Forest(Vec<String>),
+
+    /// A remap points a frame name at a frame that is not in the tree.
+    DanglingRemap {
+        /// The remapped name.
+        from: String,
+        /// The frame it resolves to.
+        to: String,
+    },
+}
+
+impl EdgeDiagnostics {
+    /// Collects the diagnostics of `buffer` relative to `now`.
+    pub(super) fn new<T>(
+        child: &str,
+        buffer: &Buffer<T>,
+        now: T,
+    ) -> Self
+    where
+        T: TimePoint,
+    {
+        let is_static = buffer.is_static();
+        let delay = if is_static {
+            None
+        } else {
+            buffer
+                .newest()
+                .and_then(|newest| now.duration_since(newest).ok())
+        };
+        Self {
+            parent: buffer.parent().unwrap_or_default().into(),
+            child: child.into(),
+            is_static,
+            rate: buffer.rate(),
+            delay,
+            span: buffer.time_span().ok(),
+            authority: buffer.authority().map(Into::into),
+        }
+    }
+}
+
+impl fmt::Display for EdgeDiagnostics {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{} -> {}: ", self.parent, self.child)?;
+        if self.is_static {
+            f.write_str("static")?;
+        } else {
+            match self.rate {
+                Some(rate) => write!(f, "{rate:.3} Hz")?,
+                None => f.write_str("rate unknown")?,
+            }
+            match self.delay {
+                Some(delay) => write!(f, ", delay {:.3} s", delay.as_secs_f64())?,
+                None => f.write_str(", delay unknown")?,
+            }
+            if let Some(span) = self.span {
+                write!(f, ", span {:.3} s", span.as_secs_f64())?;
+            }
+        }
+        match &self.authority {
+            Some(authority) => write!(f, ", authority {authority}"),
+            None => f.write_str(", authority unknown"),
+        }
+    }
+}
+
+impl<T> fmt::Display for Diagnostics<T>
+where
+    T: TimePoint,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for edge in &self.edges {
+            writeln!(f, "{edge}")?;
+        }
+        Ok(())
+    }
+}
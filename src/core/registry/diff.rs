@@ -0,0 +1,49 @@
+use alloc::string::String;
+
+use crate::{
+    geometry::Transform,
+    time::{TimePoint, Timestamp},
+};
+
+/// A difference between the edges of two registries, reported by
+/// [`Registry::diff`](super::Registry::diff).
+///
+/// Edges are identified by their child frame, which owns the edge in a
+/// registry.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum EdgeDiff<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// Only the registry `diff` was called on has an edge into the child
+    /// frame.
+    OnlyInSelf(String),
+
+    /// Only the other registry has an edge into the child frame.
+    OnlyInOther(String),
+
+    /// Both registries have an edge into the child frame, but their latest
+    /// transforms differ: translation or rotation beyond the epsilon, or a
+    /// different parent or timestamp.
+    LatestDiverges {
+        /// The latest transform of the registry `diff` was called on.
+        this: Transform<T>,
+        /// The latest transform of the other registry.
+        other: Transform<T>,
+    },
+}
+
+impl<T> EdgeDiff<T>
+where
+    T: TimePoint,
+{
+    /// Returns the child frame of the differing edge.
+    #[must_use]
+    pub fn child(&self) -> &str {
+        match self {
+            Self::OnlyInSelf(child) | Self::OnlyInOther(child) => child,
+            Self::LatestDiverges { this, .. } => &this.child,
+        }
+    }
+}
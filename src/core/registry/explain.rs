@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+
+use crate::{
+    geometry::Transform,
+    time::{TimePoint, Timestamp},
+};
+
+/// A lookup broken down edge by edge, returned by
+/// [`Registry::explain_transform`](super::Registry::explain_transform).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LookupExplanation<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The composed transform, as returned by
+    /// [`Registry::get_transform`](super::Registry::get_transform).
+    pub transform: Transform<T>,
+    /// The edges the lookup composes, ordered along the path.
+    pub edges: Vec<ExplainedEdge<T>>,
+}
+
+/// One edge of a [`LookupExplanation`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ExplainedEdge<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The edge's transform at the requested time, from its parent to its
+    /// child frame.
+    pub transform: Transform<T>,
+    /// The timestamps of the stored samples the transform was computed
+    /// from: the earlier and later sample of an interpolation, or the same
+    /// stamp twice when a single sample was used, as for static edges,
+    /// exact matches and the `Nearest`, `Hold` and `None` policies.
+    pub sources: (T, T),
+}
@@ -0,0 +1,157 @@
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+use crate::{
+    Registry,
+    core::Buffer,
+    errors::{BufferError, TransformError},
+    geometry::Transform,
+    time::{TimePoint, Timestamp},
+};
+
+/// Looks up transforms across several registries as if they were one tree.
+///
+/// Systems often keep edges with different lifecycles apart, for example a
+/// static calibration registry loaded once and a high-rate dynamic one. A
+/// group composes chains that cross between them: every edge is served by
+/// the first registry, in priority order, that stores its child frame, and
+/// frame names are resolved through the first registry that remaps them.
+///
+/// The group borrows the registries, so its view cannot change while it
+/// lives. [`RegistryGroup::new`] checks the combined tree once: an edge
+/// stored in several registries must have the same parent in all of them,
+/// and the combined edges must not form a cycle.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Registry,
+///     core::RegistryGroup,
+///     geometry::{Quaternion, Transform, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let mut calibration = Registry::<Timestamp>::new();
+/// calibration
+///     .add_static_transform(Transform {
+///         translation: Vector3::new(0.0, 0.0, 0.5),
+///         rotation: Quaternion::identity(),
+///         timestamp: Timestamp::zero(),
+///         parent: "base".into(),
+///         child: "lidar".into(),
+///     })
+///     .unwrap();
+///
+/// let mut odometry = Registry::<Timestamp>::new();
+/// let t = Timestamp::from_nanos(1_000_000_000);
+/// odometry
+///     .add_transform(Transform {
+///         translation: Vector3::new(2.0, 0.0, 0.0),
+///         rotation: Quaternion::identity(),
+///         timestamp: t,
+///         parent: "odom".into(),
+///         child: "base".into(),
+///     })
+///     .unwrap();
+///
+/// let group = RegistryGroup::new([&calibration, &odometry]).unwrap();
+/// let lidar = group.get_transform("odom", "lidar", t).unwrap();
+/// assert_eq!(lidar.translation, Vector3::new(2.0, 0.0, 0.5));
+/// ```
+#[derive(Debug)]
+pub struct RegistryGroup<'a, T = Timestamp>
+where
+    T: TimePoint,
+{
+    registries: Vec<&'a Registry<T>>,
+    /// The buffer serving each child frame, from the first registry that
+    /// stores it.
+    edges: HashMap<&'a str, &'a Buffer<T>>,
+}
+
+impl<'a, T> RegistryGroup<'a, T>
+where
+    T: TimePoint,
+{
+    /// Combines `registries`, highest priority first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BufferError::ReparentingNotSupported`, carrying the parent
+    /// in the higher-priority registry, if a child frame has different
+    /// parents in two registries. Returns `BufferError::CycleDetected` if
+    /// the combined edges form a cycle.
+    pub fn new<I>(registries: I) -> Result<Self, BufferError>
+    where
+        I: IntoIterator<Item = &'a Registry<T>>,
+    {
+        let registries: Vec<&'a Registry<T>> = registries.into_iter().collect();
+        let mut edges: HashMap<&'a str, &'a Buffer<T>> = HashMap::new();
+        for registry in &registries {
+            for (child, buffer) in &registry.data {
+                match edges.get(child.as_str()) {
+                    Some(served) if served.parent() != buffer.parent() => {
+                        return Err(BufferError::ReparentingNotSupported(
+                            served.parent().unwrap_or_default().into(),
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        edges.insert(child.as_str(), buffer);
+                    }
+                }
+            }
+        }
+
+        let cyclic = edges.iter().any(|(&child, buffer)| {
+            buffer
+                .parent()
+                .is_some_and(|parent| Registry::creates_cycle(child, parent, &edges))
+        });
+        if cyclic {
+            return Err(BufferError::CycleDetected);
+        }
+        Ok(Self { registries, edges })
+    }
+
+    /// Returns the registries of the group, highest priority first.
+    #[must_use]
+    pub fn registries(&self) -> &[&'a Registry<T>] {
+        &self.registries
+    }
+
+    /// Retrieves the transform from the `from` frame to the `to` frame at
+    /// the requested timestamp, composing edges from any of the registries.
+    ///
+    /// The chain is resolved like in [`Registry::get_transform`], over the
+    /// edges the group serves, and the result carries the requested
+    /// timestamp. Requesting a frame relative to itself returns the identity
+    /// transform.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::get_transform`] for a registry
+    /// holding the group's edges.
+    pub fn get_transform(
+        &self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+    ) -> Result<Transform<T>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        Registry::process_get_transform(from, to, timestamp, &self.edges, &Buffer::get)
+    }
+
+    /// Resolves a frame name through the first registry that remaps it.
+    fn resolve_frame<'b>(
+        &'b self,
+        frame: &'b str,
+    ) -> &'b str {
+        self.registries
+            .iter()
+            .map(|registry| registry.resolve_frame(frame))
+            .find(|&resolved| resolved != frame)
+            .unwrap_or(frame)
+    }
+}
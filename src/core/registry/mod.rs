@@ -10,6 +10,7 @@
 //! - **Automatic Buffer Cleanup**: A registry built with `Registry::with_max_age`
 //!   automatically cleans up old dynamic transforms on insert; one built with
 //!   `Registry::new` keeps them until `delete_transforms_before` is called.
+//!   `Registry::builder` sets a different maximum age per child frame.
 //!
 //! ## Usage
 //!
@@ -74,19 +75,40 @@
 //! ```
 
 use crate::{
-    core::Buffer,
+    core::{Buffer, BufferConfig},
     errors::{BufferError, TransformError},
-    geometry::{Localized, Quaternion, Transform, Vector3},
+    geometry::{Localized, Quaternion, Transform, Twist, Vector3},
     time::{TimePoint, Timestamp},
 };
 use alloc::{
     boxed::Box,
-    collections::{BTreeSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     string::String,
+    vec,
+    vec::Vec,
 };
+use approx::AbsDiffEq;
 use hashbrown::HashMap;
 
-use core::time::Duration;
+use core::{fmt, time::Duration};
+
+pub use builder::RegistryBuilder;
+pub use cache::LookupCache;
+pub use diagnostics::{Diagnostics, EdgeDiagnostics, TreeIssue};
+pub use diff::EdgeDiff;
+pub use explain::{ExplainedEdge, LookupExplanation};
+pub use group::RegistryGroup;
+pub use snapshot::Snapshot;
+pub use stats::{FrameStats, RegistryStats};
+
+mod builder;
+mod cache;
+mod diagnostics;
+mod diff;
+mod explain;
+mod group;
+mod snapshot;
+mod stats;
 
 /// A registry for managing transforms between different frames. It can
 /// traverse the parent-child tree and calculate the final transform.
@@ -96,6 +118,12 @@ use core::time::Duration;
 /// The `Registry` struct provides methods to add and retrieve transforms
 /// between frames.
 ///
+/// Frames are stored in a hash map, but nothing the registry reports depends
+/// on its iteration order. Listings come in path order or sorted by frame
+/// name, and the [`Debug`](fmt::Debug) output lists frames sorted, so two
+/// registries with the same contents print the same text however they were
+/// filled.
+///
 /// # Examples
 ///
 /// ```
@@ -139,14 +167,28 @@ use core::time::Duration;
 /// assert!(result.is_ok());
 /// assert_eq!(result.unwrap(), t_a_b_2);
 /// ```
-#[derive(Debug)]
 pub struct Registry<T = Timestamp>
 where
     T: TimePoint,
 {
     /// Maps a child frame name to the buffer of transforms into that frame.
     data: HashMap<String, Buffer<T>>,
-    max_age: Option<Duration>,
+    /// Retention settings for every buffer the registry creates.
+    config: BufferConfig,
+    /// Per-child-frame overrides of `config`'s maximum age.
+    frame_max_age: HashMap<String, Duration>,
+    /// How far behind the newest dynamic timestamp an insert may lie before
+    /// it is treated as a time jump that clears all dynamic buffers.
+    time_jump_threshold: Option<Duration>,
+    /// Upper bound on the newest stored dynamic timestamp, raised on every
+    /// insert. Removals may leave it above the stored data; a suspected time
+    /// jump is confirmed against the buffers before anything is cleared.
+    newest_dynamic: Option<T>,
+    /// User-defined key-value annotations per frame name.
+    frame_metadata: HashMap<String, BTreeMap<String, String>>,
+    /// Legacy frame names mapped to their canonical names. No canonical
+    /// name is itself a key, so resolving a name twice changes nothing.
+    remaps: HashMap<String, String>,
 }
 
 impl<T> Registry<T>
@@ -168,10 +210,7 @@ where
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            data: HashMap::new(),
-            max_age: None,
-        }
+        Self::with_config(BufferConfig::new())
     }
 
     /// Creates a new `Registry` with automatic cleanup after `max_age`.
@@ -191,12 +230,83 @@ where
     /// ```
     #[must_use]
     pub fn with_max_age(max_age: Duration) -> Self {
+        Self::with_config(BufferConfig::new().with_max_age(max_age))
+    }
+
+    /// Creates a new `Registry` with room for at least `frames` child frames
+    /// before its frame map reallocates.
+    ///
+    /// Systems that know the size of their tree avoid rehashing spikes while
+    /// the frames are first published. Transforms are kept until removed
+    /// manually, as with [`Registry::new`]. Buffers store their samples in
+    /// ordered trees that allocate per node and cannot be pre-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{Registry, time::Timestamp};
+    ///
+    /// let registry = Registry::<Timestamp>::with_capacity(200);
+    /// ```
+    #[must_use]
+    pub fn with_capacity(frames: usize) -> Self {
+        Self {
+            data: HashMap::with_capacity(frames),
+            ..Self::new()
+        }
+    }
+
+    /// Creates a new `Registry` whose buffers use the given retention
+    /// settings.
+    ///
+    /// Every child frame's buffer is created with `config`, so a
+    /// `max_entries` limit applies per child frame, not to the registry as a
+    /// whole. Static transforms are never trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{num::NonZeroUsize, time::Duration};
+    /// use transforms::{Registry, core::BufferConfig, time::Timestamp};
+    ///
+    /// let config = BufferConfig::new()
+    ///     .with_max_age(Duration::from_secs(60))
+    ///     .with_max_entries(NonZeroUsize::new(1000).unwrap());
+    /// let registry = Registry::<Timestamp>::with_config(config);
+    /// ```
+    #[must_use]
+    pub fn with_config(config: BufferConfig) -> Self {
         Self {
             data: HashMap::new(),
-            max_age: Some(max_age),
+            config,
+            frame_max_age: HashMap::new(),
+            time_jump_threshold: None,
+            newest_dynamic: None,
+            frame_metadata: HashMap::new(),
+            remaps: HashMap::new(),
         }
     }
 
+    /// Returns a builder for a registry with per-child-frame retention
+    /// settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{Registry, time::Timestamp};
+    ///
+    /// // Keep odometry for a minute, everything else for two seconds.
+    /// let registry: Registry<Timestamp> = Registry::builder()
+    ///     .default_max_age(Duration::from_secs(2))
+    ///     .frame_max_age("odom", Duration::from_secs(60))
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn builder() -> RegistryBuilder<T> {
+        RegistryBuilder::new()
+    }
+
     /// Adds a transform to the registry.
     ///
     /// # Errors
@@ -210,10 +320,16 @@ where
     /// validation (non-finite values or a non-unit rotation),
     /// `BufferError::SelfReferentialFrame` if its parent and child are the
     /// same frame, `BufferError::ReparentingNotSupported` if the child frame
-    /// already has a different parent (remove the frame first with
-    /// [`Registry::remove_frame`]), and `BufferError::CycleDetected` if the
+    /// already has a different parent (move it with [`Registry::reparent`]
+    /// instead), and `BufferError::CycleDetected` if the
     /// new relationship would create a cycle in the frame tree.
     ///
+    /// In a registry built with [`RegistryBuilder::clear_on_time_jump`], a
+    /// dynamic transform stamped more than the threshold before the newest
+    /// dynamic transform replaces every dynamic buffer: it is inserted into
+    /// a registry holding only the static transforms. If that insert fails,
+    /// the dynamic history is kept.
+    ///
     /// # Examples
     ///
     /// ```
@@ -238,7 +354,97 @@ where
         &mut self,
         t: Transform<T>,
     ) -> Result<(), BufferError> {
-        Self::process_add_transform(t, &mut self.data, self.max_age)
+        self.insert_with_authority(t, None)
+    }
+
+    /// Adds a transform and records which component wrote it.
+    ///
+    /// The authority, such as a node or driver name, is kept per child
+    /// frame for the latest write and reported by [`Buffer::authority`] and
+    /// [`Registry::stats`], so a conflicting or stale edge can be traced to
+    /// its publisher. A later [`Registry::add_transform`] without an
+    /// authority clears it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::add_transform`]. A rejected
+    /// transform leaves the recorded authority unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// let transform = Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp: Timestamp::from_nanos(1_000),
+    ///     parent: "odom".into(),
+    ///     child: "base".into(),
+    /// };
+    ///
+    /// registry
+    ///     .add_transform_with_authority(transform, "wheel_odometry")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     registry.buffer("base").unwrap().authority(),
+    ///     Some("wheel_odometry")
+    /// );
+    /// ```
+    pub fn add_transform_with_authority(
+        &mut self,
+        t: Transform<T>,
+        authority: &str,
+    ) -> Result<(), BufferError> {
+        self.insert_with_authority(t, Some(authority))
+    }
+
+    /// Adds a transform as static, valid for all time.
+    ///
+    /// The transform's timestamp is replaced by the static timestamp value
+    /// (see [`TimePoint::static_timestamp`]), so the caller states the intent
+    /// instead of relying on a zero stamp. [`Registry::add_transform`] still
+    /// treats a transform stamped with the static value as static.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::add_transform`]; in particular
+    /// `BufferError::StaticDynamicConflict` if the child frame already holds
+    /// dynamic transforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// let mounting = Transform {
+    ///     translation: Vector3::new(0.0, 0.0, 0.3),
+    ///     rotation: Quaternion::identity(),
+    ///     // The stamp is irrelevant: the transform is stored as static.
+    ///     timestamp: Timestamp::from_nanos(42),
+    ///     parent: "base".into(),
+    ///     child: "lidar".into(),
+    /// };
+    ///
+    /// registry.add_static_transform(mounting).unwrap();
+    /// assert!(registry.is_static("base", "lidar").unwrap());
+    /// ```
+    pub fn add_static_transform(
+        &mut self,
+        mut t: Transform<T>,
+    ) -> Result<(), BufferError> {
+        t.timestamp = T::static_timestamp();
+        self.add_transform(t)
     }
 
     /// Retrieves the transform from the `from` frame to the `to` frame at
@@ -304,62 +510,1627 @@ where
         to: &str,
         timestamp: T,
     ) -> Result<Transform<T>, TransformError> {
-        Self::process_get_transform(from, to, timestamp, &self.data)
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        Self::process_get_transform(from, to, timestamp, &self.data, &Buffer::get)
     }
 
-    /// Retrieves a transform for a specific value into `target_frame`.
+    /// Returns the per-edge transforms a lookup between `from` and `to` at
+    /// `timestamp` composes, for debugging unexpected results or reading
+    /// intermediate frames.
     ///
-    /// The source frame and timestamp are taken from the value.
+    /// The edges are ordered along the path from `from` up to the closest
+    /// common ancestor and down to `to`. Each is sampled like in
+    /// [`Registry::get_transform`], but kept as stored, from its parent to
+    /// its child frame, without inverting edges walked upwards. A frame
+    /// relative to itself has an empty chain.
     ///
-    /// If the value is already in `target_frame`, this returns an identity
-    /// transform with `parent == child == target_frame` and the value's
-    /// timestamp (via `get_transform`'s same-frame identity).
+    /// # Errors
+    ///
+    /// * `TransformError::UnknownFrame` - If a requested frame exists nowhere in the tree
+    /// * `TransformError::Disconnected` - If both frames exist but no chain connects them
+    /// * `TransformError::NotFoundAt` - If an edge on the path cannot serve the requested time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (parent, child) in [("map", "base"), ("base", "camera")] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::zero(),
+    ///             parent: parent.into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let chain = registry
+    ///     .transform_chain("map", "camera", Timestamp::from_nanos(5))
+    ///     .unwrap();
+    /// let children: Vec<&str> = chain.iter().map(|tf| tf.child.as_str()).collect();
+    /// assert_eq!(children, ["base", "camera"]);
+    /// ```
+    pub fn transform_chain(
+        &self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+    ) -> Result<Vec<Transform<T>>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        self.sample_path(from, to, &|buffer| buffer.get(&timestamp))
+    }
+
+    /// Explains a lookup edge by edge: returns the transform
+    /// [`Registry::get_transform`] would, together with every edge it
+    /// composes and the stored samples each edge was computed from.
+    ///
+    /// Use it to pinpoint which edge introduced an error: an edge served
+    /// from samples far apart in time, or held from an old sample, shows up
+    /// in its [`ExplainedEdge::sources`]. The edges are ordered and kept as
+    /// stored like in [`Registry::transform_chain`].
     ///
     /// # Errors
     ///
-    /// Returns a `TransformError` if a transform cannot be resolved.
-    pub fn get_transform_for<U>(
+    /// Returns the same errors as [`Registry::get_transform`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for secs in [1, 3] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let query = Timestamp::from_nanos(2_000_000_000);
+    /// let explanation = registry.explain_transform("map", "base", query).unwrap();
+    /// assert_eq!(
+    ///     explanation.transform.translation,
+    ///     Vector3::new(2.0, 0.0, 0.0)
+    /// );
+    /// assert_eq!(
+    ///     explanation.edges[0].sources,
+    ///     (
+    ///         Timestamp::from_nanos(1_000_000_000),
+    ///         Timestamp::from_nanos(3_000_000_000)
+    ///     )
+    /// );
+    /// ```
+    pub fn explain_transform(
         &self,
-        value: &U,
-        target_frame: &str,
-    ) -> Result<Transform<T>, TransformError>
-    where
-        U: Localized<T>,
-    {
-        self.get_transform(target_frame, value.frame(), value.timestamp())
+        from: &str,
+        to: &str,
+        timestamp: T,
+    ) -> Result<LookupExplanation<T>, TransformError> {
+        let transform = self.get_transform(from, to, timestamp)?;
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        let edges = self.sample_path(from, to, &|buffer| {
+            buffer
+                .get_with_sources(&timestamp)
+                .map(|(transform, sources)| ExplainedEdge { transform, sources })
+        })?;
+        Ok(LookupExplanation { transform, edges })
     }
 
-    /// Retrieves a transform between two frames at different timestamps using a fixed frame.
+    /// Returns the frames on the path from `from` to `to`, both included,
+    /// without sampling any transform.
     ///
-    /// This is the "time travel" API that allows you to get the transform from a source frame
-    /// at one time to a target frame at a different time. This is useful for scenarios like
-    /// tracking an object that was detected on a moving platform (e.g., a conveyor belt) and
-    /// getting its current position in a static world frame.
+    /// The path runs up to the closest common ancestor and down to `to`.
+    /// Each frame has a single parent, so the path does not depend on time;
+    /// use it to validate the tree or to highlight a lookup's route. A frame
+    /// relative to itself is a path of one frame.
     ///
-    /// The algorithm works by:
-    /// 1. Computing the transform that expresses `source_frame` in `fixed_frame` at `source_time`
-    /// 2. Computing the transform that expresses `target_frame` in `fixed_frame` at `target_time`
-    /// 3. Combining the two into the requested transform
+    /// # Errors
     ///
-    /// `fixed_frame` is a frame that does not change over time, used as an
-    /// intermediate reference point (typically a world or map frame).
+    /// * `TransformError::UnknownFrame` - If a requested frame exists nowhere in the tree
+    /// * `TransformError::Disconnected` - If both frames exist but no chain connects them
     ///
-    /// Either endpoint may coincide with `fixed_frame`: that leg is then the
-    /// identity, so only the other leg is resolved. When `source_frame` and
-    /// `target_frame` both coincide with it, the result is the identity
-    /// transform carrying `target_time`.
+    /// # Examples
     ///
-    /// # Choosing the fixed frame
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (parent, child) in [("map", "base"), ("base", "camera"), ("map", "dock")] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::zero(),
+    ///             parent: parent.into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let path = registry.path_between("camera", "dock").unwrap();
+    /// assert_eq!(path, ["camera", "base", "map", "dock"]);
+    /// ```
+    pub fn path_between<'a>(
+        &'a self,
+        from: &'a str,
+        to: &'a str,
+    ) -> Result<Vec<&'a str>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        let Some((mut up, mut down)) = Self::split_path(from, to, &self.data) else {
+            return Err(Self::diagnose_not_found(from, to, &self.data, &mut None));
+        };
+        down.pop();
+        up.extend(down.into_iter().rev());
+        Ok(up)
+    }
+
+    /// Returns `true` if a chain of edges connects `a` and `b`, whatever the
+    /// times their buffers cover.
+    ///
+    /// Startup code can verify the tree topology with it before data starts
+    /// flowing; a lookup may still fail for a time an edge does not cover. A
+    /// frame is connected to itself, matching the identity that lookups
+    /// return for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (parent, child) in [("map", "base"), ("base", "lidar"), ("world", "island")] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(1),
+    ///             parent: parent.into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert!(registry.is_connected("lidar", "map"));
+    /// assert!(!registry.is_connected("lidar", "island"));
+    /// ```
+    #[must_use]
+    pub fn is_connected(
+        &self,
+        a: &str,
+        b: &str,
+    ) -> bool {
+        let (a, b) = (self.resolve_frame(a), self.resolve_frame(b));
+        Self::split_path(a, b, &self.data).is_some()
+    }
+
+    /// Returns the root frames, which appear only as parents, sorted by
+    /// name.
+    ///
+    /// A single tree has one root. More than one means the registry holds a
+    /// forest, the most common cause of `TransformError::Disconnected`:
+    /// usually a missing edge, or a frame published under two names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (parent, child) in [("map", "odom"), ("odom", "base"), ("base_link", "lidar")] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::zero(),
+    ///             parent: parent.into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// // `lidar` hangs off `base_link` instead of `base`.
+    /// assert_eq!(registry.roots(), ["base_link", "map"]);
+    /// ```
+    #[must_use]
+    pub fn roots(&self) -> Vec<&str> {
+        let roots: BTreeSet<&str> = self
+            .data
+            .values()
+            .filter_map(Buffer::parent)
+            .filter(|parent| !self.data.contains_key(*parent))
+            .collect();
+        roots.into_iter().collect()
+    }
+
+    /// Checks the structure of the registry and returns the problems found,
+    /// or an empty list for a single, fully resolvable tree.
+    ///
+    /// Reports a forest of several trees (frames orphaned from the main
+    /// tree) and remaps onto frames that are not in the tree. Cycles, frames
+    /// with more than one parent, empty buffers and static/dynamic conflicts
+    /// are rejected when inserted (see [`BufferError`]), so they never
+    /// appear here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     core::TreeIssue,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let edge = |parent: &str, child: &str| Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp: Timestamp::zero(),
+    ///     parent: parent.into(),
+    ///     child: child.into(),
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// registry.add_transform(edge("map", "base")).unwrap();
+    /// registry.add_transform(edge("base_link", "lidar")).unwrap();
+    /// assert_eq!(
+    ///     registry.validate(),
+    ///     [TreeIssue::Forest(vec!["base_link".into(), "map".into()])]
+    /// );
+    ///
+    /// registry.remap_frame("base_footprint", "base_link");
+    /// registry.add_transform(edge("base", "base_link")).unwrap();
+    /// assert!(registry.validate().is_empty());
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> Vec<TreeIssue> {
+        let mut issues = Vec::new();
+
+        let roots = self.roots();
+        if roots.len() > 1 {
+            issues.push(TreeIssue::Forest(
+                roots.into_iter().map(Into::into).collect(),
+            ));
+        }
+
+        let mut dangling: Vec<(&String, &String)> = self
+            .remaps
+            .iter()
+            .filter(|(_, to)| !Self::frame_exists(to, &self.data))
+            .collect();
+        dangling.sort_unstable();
+        issues.extend(
+            dangling
+                .into_iter()
+                .map(|(from, to)| TreeIssue::DanglingRemap {
+                    from: from.clone(),
+                    to: to.clone(),
+                }),
+        );
+
+        issues
+    }
+
+    /// Returns the ancestors of `frame`, from its parent up to the root of
+    /// its tree.
+    ///
+    /// Each frame has a single parent, replaced only by
+    /// [`Registry::reparent`], so the walk does not depend on time. A root
+    /// or unknown frame has no ancestors. Combine it with
+    /// [`Registry::is_static`] to ask whether a sensor is rigidly attached
+    /// under a frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (parent, child) in [("map", "base"), ("base", "lidar"), ("base", "camera")] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::zero(),
+    ///             parent: parent.into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert_eq!(registry.ancestors("lidar"), ["base", "map"]);
+    /// assert_eq!(registry.descendants("map"), ["base", "camera", "lidar"]);
+    /// assert!(registry.ancestors("lidar").contains(&"base"));
+    /// assert!(registry.is_static("base", "lidar").unwrap());
+    /// ```
+    #[must_use]
+    pub fn ancestors<'a>(
+        &'a self,
+        frame: &'a str,
+    ) -> Vec<&'a str> {
+        Self::ancestors_of(self.resolve_frame(frame), &self.data)
+            .into_iter()
+            .skip(1)
+            .collect()
+    }
+
+    /// Returns the descendants of `frame`: every frame below it in the tree,
+    /// breadth-first with siblings sorted by name.
+    ///
+    /// Like [`Registry::ancestors`], the walk does not depend on time. A leaf
+    /// or unknown frame has no descendants.
+    #[must_use]
+    pub fn descendants<'a>(
+        &'a self,
+        frame: &'a str,
+    ) -> Vec<&'a str> {
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (child, buffer) in &self.data {
+            if let Some(parent) = buffer.parent() {
+                children.entry(parent).or_default().push(child);
+            }
+        }
+
+        let mut descendants = Vec::new();
+        let mut queue = VecDeque::from([self.resolve_frame(frame)]);
+        // Acyclic by construction; the length bound is a defensive backstop.
+        while let Some(current) = queue.pop_front() {
+            if descendants.len() > self.data.len() {
+                break;
+            }
+            if let Some(mut below) = children.remove(current) {
+                below.sort_unstable();
+                descendants.extend_from_slice(&below);
+                queue.extend(below);
+            }
+        }
+        descendants
+    }
+
+    /// Freezes the registry at `timestamp`.
+    ///
+    /// Returns a [`Snapshot`] holding the transform of every edge resolved
+    /// at that instant; edges that cannot serve the timestamp are left out.
+    /// The snapshot also copies the remap table, so lookups accept the same
+    /// legacy frame names. It is independent of the registry, so later
+    /// inserts, cleanups and remaps do not affect it.
+    #[must_use]
+    pub fn freeze_at(
+        &self,
+        timestamp: T,
+    ) -> Snapshot<T> {
+        Snapshot::new(timestamp, &self.data, self.remaps.clone())
+    }
+
+    /// Compares the edges of two registries.
+    ///
+    /// Reports every child frame with an edge in only one of the registries,
+    /// and every shared edge whose latest transforms differ: translation or
+    /// rotation components beyond `epsilon` (see the [`AbsDiffEq`] impl of
+    /// [`Transform`]), or a different parent or timestamp. Only the latest
+    /// transform of each edge is compared, not the full history. The result
+    /// is sorted by child frame and empty if the registries agree, which
+    /// makes it suitable for checking that a replayed log reproduces a live
+    /// system's tree.
+    ///
+    /// [`AbsDiffEq`]: approx::AbsDiffEq
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     core::EdgeDiff,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let transform = Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp: Timestamp::from_nanos(1_000_000_000),
+    ///     parent: "map".into(),
+    ///     child: "base".into(),
+    /// };
+    /// let mut live = Registry::<Timestamp>::new();
+    /// live.add_transform(transform.clone()).unwrap();
+    /// let mut replay = Registry::<Timestamp>::new();
+    /// replay.add_transform(transform).unwrap();
+    ///
+    /// assert!(live.diff(&replay, 1e-9).is_empty());
+    ///
+    /// replay.remove_frame("base");
+    /// assert_eq!(
+    ///     live.diff(&replay, 1e-9),
+    ///     vec![EdgeDiff::OnlyInSelf("base".into())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(
+        &self,
+        other: &Self,
+        epsilon: f64,
+    ) -> Vec<EdgeDiff<T>> {
+        let children: BTreeSet<&String> = self.data.keys().chain(other.data.keys()).collect();
+        children
+            .into_iter()
+            .filter_map(|child| {
+                let this = self.data.get(child).and_then(Buffer::get_latest);
+                let theirs = other.data.get(child).and_then(Buffer::get_latest);
+                match (this, theirs) {
+                    (Some(_), None) => Some(EdgeDiff::OnlyInSelf(child.clone())),
+                    (None, Some(_)) => Some(EdgeDiff::OnlyInOther(child.clone())),
+                    (Some(this), Some(theirs)) if !this.abs_diff_eq(theirs, epsilon) => {
+                        Some(EdgeDiff::LatestDiverges {
+                            this: this.clone(),
+                            other: theirs.clone(),
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns size and memory statistics for every child frame.
+    ///
+    /// Operators can poll this to monitor transform memory and spot runaway
+    /// publishers: a frame whose `len` keeps growing is missing a retention
+    /// limit (see [`BufferConfig`]). The byte counts are estimates, see
+    /// [`FrameStats::estimated_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for nanos in [1, 2, 3] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(nanos),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let stats = registry.stats();
+    /// assert_eq!(stats.total_transforms, 3);
+    /// assert_eq!(stats.frames[0].newest, Some(Timestamp::from_nanos(3)));
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> RegistryStats<T> {
+        let mut frames: Vec<FrameStats<T>> = self
+            .data
+            .iter()
+            .map(|(child, buffer)| FrameStats::new(child, buffer))
+            .collect();
+        frames.sort_unstable_by(|a, b| a.child.cmp(&b.child));
+        RegistryStats {
+            total_transforms: frames.iter().map(|frame| frame.len).sum(),
+            estimated_bytes: frames.iter().map(|frame| frame.estimated_bytes).sum(),
+            frames,
+        }
+    }
+
+    /// Returns the dynamic edges whose newest transform is more than
+    /// `max_silence` older than `now`, sorted by child frame.
+    ///
+    /// Watchdogs poll this to notice a publisher that silently stopped,
+    /// such as a crashed sensor driver. The registry never reads a clock, so
+    /// the caller supplies `now`, usually the current time of the same clock
+    /// that stamps the transforms. Static edges never go stale, and an edge
+    /// stamped after `now` is not reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (child, seconds) in [("base", 10), ("lidar", 4)] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::zero(),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_millis(seconds * 1_000),
+    ///             parent: "odom".into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let now = Timestamp::from_millis(10_500);
+    /// let stale = registry.stale_frames(now, Duration::from_secs(1));
+    /// assert_eq!(stale.len(), 1);
+    /// assert_eq!(stale[0].child, "lidar");
+    /// ```
+    #[must_use]
+    pub fn stale_frames(
+        &self,
+        now: T,
+        max_silence: Duration,
+    ) -> Vec<FrameStats<T>> {
+        let mut frames: Vec<FrameStats<T>> = self
+            .data
+            .iter()
+            .filter(|(_, buffer)| !buffer.is_static())
+            .filter(|(_, buffer)| {
+                buffer.newest().is_some_and(|newest| {
+                    now.duration_since(newest)
+                        .is_ok_and(|silence| silence > max_silence)
+                })
+            })
+            .map(|(child, buffer)| FrameStats::new(child, buffer))
+            .collect();
+        frames.sort_unstable_by(|a, b| a.child.cmp(&b.child));
+        frames
+    }
+
+    /// Returns a health report of every edge: update rate, delay of the
+    /// newest transform behind `now`, buffered time span and authority,
+    /// sorted by child frame.
+    ///
+    /// The registry never reads a clock, so the caller supplies `now`. The
+    /// report prints one line per edge with `{}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for millis in [1_000, 1_100, 1_200] {
+    ///     registry
+    ///         .add_transform_with_authority(
+    ///             Transform {
+    ///                 translation: Vector3::zero(),
+    ///                 rotation: Quaternion::identity(),
+    ///                 timestamp: Timestamp::from_millis(millis),
+    ///                 parent: "odom".into(),
+    ///                 child: "base".into(),
+    ///             },
+    ///             "wheels",
+    ///         )
+    ///         .unwrap();
+    /// }
+    ///
+    /// let report = registry.diagnostics(Timestamp::from_millis(1_500));
+    /// assert_eq!(report.edges[0].delay, Some(Duration::from_millis(300)));
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "odom -> base: 10.000 Hz, delay 0.300 s, span 0.200 s, authority wheels\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diagnostics(
+        &self,
+        now: T,
+    ) -> Diagnostics<T> {
+        let mut edges: Vec<EdgeDiagnostics> = self
+            .data
+            .iter()
+            .map(|(child, buffer)| EdgeDiagnostics::new(child, buffer, now))
+            .collect();
+        edges.sort_unstable_by(|a, b| a.child.cmp(&b.child));
+        Diagnostics { now, edges }
+    }
+
+    /// Returns the buffer storing the transforms into `child`, if any.
+    ///
+    /// Use it to inspect a frame's coverage (for example
+    /// [`Buffer::time_span`]) before querying.
+    #[must_use]
+    pub fn buffer(
+        &self,
+        child: &str,
+    ) -> Option<&Buffer<T>> {
+        self.data.get(child)
+    }
+
+    /// Returns an iterator over every stored transform as
+    /// `(parent, child, transform)`, for exporters, debuggers and custom
+    /// persistence.
+    ///
+    /// The transforms are borrowed as stored, without interpolation, sorted
+    /// by child frame and then by timestamp, so the order is the same on
+    /// every run. Static transforms carry the static timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for (parent, child) in [("map", "base"), ("base", "camera")] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::zero(),
+    ///             parent: parent.into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let edges: Vec<(&str, &str)> = registry
+    ///     .iter_transforms()
+    ///     .map(|(parent, child, _)| (parent, child))
+    ///     .collect();
+    /// assert_eq!(edges, [("map", "base"), ("base", "camera")]);
+    /// ```
+    pub fn iter_transforms(&self) -> impl Iterator<Item = (&str, &str, &Transform<T>)> {
+        sorted(&self.data)
+            .into_values()
+            .flat_map(Buffer::entries)
+            .map(|tf| (tf.parent.as_str(), tf.child.as_str(), tf))
+    }
+
+    /// Attaches a metadata entry, such as a description, a sensor serial
+    /// number, or a calibration date, to a frame.
+    ///
+    /// Metadata is keyed by frame name and independent of the stored
+    /// transforms: it may be set before a frame's first transform arrives,
+    /// follows [`Registry::rename_frame`], and is dropped by
+    /// [`Registry::remove_frame`]. Returns the previous value of `key`, if
+    /// any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{Registry, time::Timestamp};
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// registry.set_frame_metadata("lidar", "serial", "LX-20931");
+    /// registry.set_frame_metadata("lidar", "calibrated", "2026-03-02");
+    ///
+    /// let metadata = registry.frame_metadata("lidar").unwrap();
+    /// assert_eq!(metadata.get("serial").map(String::as_str), Some("LX-20931"));
+    /// ```
+    pub fn set_frame_metadata(
+        &mut self,
+        frame: &str,
+        key: &str,
+        value: &str,
+    ) -> Option<String> {
+        self.frame_metadata
+            .entry_ref(frame)
+            .or_default()
+            .insert(key.into(), value.into())
+    }
+
+    /// Returns the metadata attached to a frame, ordered by key, or `None`
+    /// if the frame has none.
+    #[must_use]
+    pub fn frame_metadata(
+        &self,
+        frame: &str,
+    ) -> Option<&BTreeMap<String, String>> {
+        self.frame_metadata.get(frame)
+    }
+
+    /// Removes a metadata entry from a frame and returns its value, if any.
+    pub fn remove_frame_metadata(
+        &mut self,
+        frame: &str,
+        key: &str,
+    ) -> Option<String> {
+        let metadata = self.frame_metadata.get_mut(frame)?;
+        let value = metadata.remove(key);
+        if metadata.is_empty() {
+            self.frame_metadata.remove(frame);
+        }
+        value
+    }
+
+    /// Maps a legacy frame name to its current name.
+    ///
+    /// Transforms added afterwards have `old` replaced by `new` in their
+    /// parent and child frames, and lookups, [`Registry::is_static`] and
+    /// [`Registry::reparent`] accept `old` wherever a frame is named, so
+    /// data recorded with legacy names works against a renamed tree.
+    /// Results always carry the current names. Remaps compose: after
+    /// `a -> b` and `b -> c`, both `a` and `b` resolve to `c`; remapping a
+    /// frame back onto one of its legacy names replaces the reverse remap.
+    /// Stored transforms are not rewritten; use [`Registry::rename_frame`]
+    /// for that. Remapping a frame onto itself removes its remap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// registry.remap_frame("base_footprint", "base");
+    ///
+    /// // A legacy publisher still uses the old name.
+    /// registry
+    ///     .add_transform(Transform {
+    ///         translation: Vector3::new(1.0, 0.0, 0.0),
+    ///         rotation: Quaternion::identity(),
+    ///         timestamp: Timestamp::zero(),
+    ///         parent: "map".into(),
+    ///         child: "base_footprint".into(),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let result = registry
+    ///     .get_transform("map", "base", Timestamp::zero())
+    ///     .unwrap();
+    /// assert_eq!(result.child, "base");
+    /// ```
+    pub fn remap_frame(
+        &mut self,
+        old: &str,
+        new: &str,
+    ) {
+        if old == new {
+            self.remaps.remove(old);
+            return;
+        }
+        let target: String = match self.remaps.get(new) {
+            Some(target) if target != old => target.clone(),
+            _ => new.into(),
+        };
+        self.remaps.remove(&target);
+        for canonical in self.remaps.values_mut() {
+            if *canonical == old {
+                canonical.clone_from(&target);
+            }
+        }
+        self.remaps.insert(old.into(), target);
+    }
+
+    /// Removes the remap of a legacy frame name and returns the name it
+    /// mapped to, if any.
+    pub fn remove_remap(
+        &mut self,
+        old: &str,
+    ) -> Option<String> {
+        self.remaps.remove(old)
+    }
+
+    /// Returns the current name of a frame after applying the remap table,
+    /// or `frame` itself if it is not remapped.
+    #[must_use]
+    pub fn resolve_frame<'a>(
+        &'a self,
+        frame: &'a str,
+    ) -> &'a str {
+        self.remaps.get(frame).map_or(frame, String::as_str)
+    }
+
+    /// Returns `true` if every edge between the `from` and `to` frames is
+    /// static, so a lookup between them gives the same answer at any time.
+    ///
+    /// A frame is static relative to itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TransformError::UnknownFrame` if a frame exists nowhere in
+    /// the tree and `TransformError::Disconnected` if no chain connects the
+    /// frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// let transform = |parent: &str, child: &str, timestamp| Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp,
+    ///     parent: parent.into(),
+    ///     child: child.into(),
+    /// };
+    /// registry
+    ///     .add_transform(transform("map", "base", Timestamp::from_nanos(1)))
+    ///     .unwrap();
+    /// registry
+    ///     .add_static_transform(transform("base", "lidar", Timestamp::zero()))
+    ///     .unwrap();
+    ///
+    /// assert!(registry.is_static("base", "lidar").unwrap());
+    /// assert!(!registry.is_static("map", "lidar").unwrap());
+    /// ```
+    pub fn is_static(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<bool, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        for frame in [from, to] {
+            if !Self::frame_exists(frame, &self.data) {
+                return Err(TransformError::UnknownFrame(frame.into()));
+            }
+        }
+        if Self::common_ancestor(from, to, &self.data).is_none() {
+            return Err(TransformError::Disconnected(from.into(), to.into()));
+        }
+        Ok(Self::dynamic_path_buffers(from, to, &self.data).is_empty())
+    }
+
+    /// Retrieves the most recent transform from the `from` frame to the `to`
+    /// frame.
+    ///
+    /// The lookup runs at the latest time every dynamic edge on the path
+    /// between the frames can serve: the oldest of the edges' newest
+    /// timestamps. Along a purely static path it runs at the static
+    /// timestamp. The result carries that time, so callers that "just want
+    /// the newest pose" do not need to track timestamps themselves.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::get_transform`]; in particular
+    /// `TransformError::NotFoundAt` if the edges on the path share no common
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for secs in [1, 2] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let latest = registry.latest_transform("map", "base").unwrap();
+    /// assert_eq!(latest.timestamp, Timestamp::from_nanos(2_000_000_000));
+    /// ```
+    pub fn latest_transform(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Transform<T>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        // If the frames are disconnected, the path is empty and the lookup
+        // reports why.
+        let timestamp = Self::dynamic_path_buffers(from, to, &self.data)
+            .iter()
+            .filter_map(|buffer| buffer.newest())
+            .min()
+            .unwrap_or_else(T::static_timestamp);
+
+        self.get_transform(from, to, timestamp)
+    }
+
+    /// Retrieves the transform from the `from` frame to the `to` frame at
+    /// the time closest to `timestamp`, within `tolerance`, that every edge
+    /// can serve.
+    ///
+    /// If the transform can be resolved at `timestamp`, this is
+    /// [`Registry::get_transform`]. Otherwise the lookup moves into
+    /// `timestamp ± tolerance`, within the covered range of every dynamic
+    /// edge on the path: first to the nearest covered time, then to the
+    /// stored sample times of those edges, nearest first, until every edge
+    /// can serve the time. Sample times help edges that cannot interpolate
+    /// there, with `InterpolationPolicy::None` or a maximum interpolation
+    /// gap. The result is stamped with the time it was actually resolved at,
+    /// so callers can see how far it was moved. This matches how many
+    /// perception stacks treat "close enough" extrinsics; it never
+    /// extrapolates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `get_transform(from, to, timestamp)` if no time
+    /// within the tolerance can be served by every edge on the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for secs in [1, 2] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "base".into(),
+    ///             child: "camera".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// // 20 ms past the newest sample: close enough.
+    /// let t = Timestamp::from_nanos(2_020_000_000);
+    /// let tolerance = Duration::from_millis(50);
+    /// let result = registry
+    ///     .get_transform_with_tolerance("base", "camera", t, tolerance)
+    ///     .unwrap();
+    /// assert_eq!(result.timestamp, Timestamp::from_nanos(2_000_000_000));
+    /// ```
+    pub fn get_transform_with_tolerance(
+        &self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+        tolerance: Duration,
+    ) -> Result<Transform<T>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        let error = match self.get_transform(from, to, timestamp) {
+            Ok(result) => return Ok(result),
+            Err(error) => error,
+        };
+
+        // The times every dynamic edge covers, intersected with the
+        // tolerance window. Unrepresentable window ends leave that side
+        // bounded by the coverage alone.
+        let buffers = Self::dynamic_path_buffers(from, to, &self.data);
+        let mut lower = buffers.iter().filter_map(|buffer| buffer.oldest()).max();
+        let mut upper = buffers.iter().filter_map(|buffer| buffer.newest()).min();
+        if let Ok(start) = timestamp.checked_sub(tolerance) {
+            lower = lower.max(Some(start));
+        }
+        if let Ok(end) = timestamp.checked_add(tolerance) {
+            upper = upper.map(|upper| upper.min(end));
+        }
+
+        let (Some(lower), Some(upper)) = (lower, upper) else {
+            return Err(error);
+        };
+        if lower > upper {
+            return Err(error);
+        }
+
+        let distance = |t: &T| {
+            let distance = if *t < timestamp {
+                timestamp.duration_since(*t)
+            } else {
+                t.duration_since(timestamp)
+            };
+            distance.unwrap_or(Duration::MAX)
+        };
+        let mut candidates: Vec<T> = buffers
+            .iter()
+            .flat_map(|buffer| buffer.range(lower..=upper))
+            .map(|transform| transform.timestamp)
+            .chain([timestamp.clamp(lower, upper)])
+            .filter(|&t| t != timestamp)
+            .collect();
+        candidates.sort_unstable_by_key(|t| (distance(t), *t));
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .find_map(|t| self.get_transform(from, to, t).ok())
+            .ok_or(error)
+    }
+
+    /// Retrieves the transform from the `from` frame to the `to` frame at
+    /// exactly the given timestamp, without interpolation.
+    ///
+    /// Every dynamic edge on the path must hold a transform stamped exactly
+    /// at `timestamp` (see [`Buffer::get_exact`]); static edges are valid for
+    /// all time. Use it when interpolating between samples would be
+    /// semantically wrong, for example for discrete keyframes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::get_transform`], with a missing
+    /// exact sample reported as `TransformError::NotFoundAt` wrapping
+    /// `BufferError::NoExactMatch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for secs in [1, 3] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "keyframe".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let t3 = Timestamp::from_nanos(3_000_000_000);
+    /// assert!(registry.get_transform_exact("map", "keyframe", t3).is_ok());
+    /// let t2 = Timestamp::from_nanos(2_000_000_000);
+    /// assert!(registry.get_transform_exact("map", "keyframe", t2).is_err());
+    /// ```
+    pub fn get_transform_exact(
+        &self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+    ) -> Result<Transform<T>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        Self::process_get_transform(from, to, timestamp, &self.data, &Buffer::get_exact)
+    }
+
+    /// Retrieves the transform from the `from` frame to the `to` frame using
+    /// the closest sample of every edge instead of interpolating.
+    ///
+    /// Each dynamic edge on the path contributes its stored transform
+    /// closest to `timestamp`, within `tolerance` if given (see
+    /// [`Buffer::get_closest`]). The samples are composed as if taken at
+    /// `timestamp`, and the result carries `timestamp`. Every dynamic edge
+    /// must cover `timestamp`: a sample is never carried beyond the range of
+    /// its buffer. Use it when the data
+    /// between samples is genuinely piecewise-constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::get_transform`]. An edge that
+    /// does not cover `timestamp` is reported as `TransformError::NotFoundAt`
+    /// wrapping `TransformError::TimestampOutOfRange`, and an edge without a
+    /// sample close enough as `TransformError::NotFoundAt` wrapping
+    /// `BufferError::NoSampleWithinTolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for secs in [1, 3] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "map".into(),
+    ///             child: "gear".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let t = Timestamp::from_nanos(1_200_000_000);
+    /// let result = registry
+    ///     .get_transform_nearest("map", "gear", t, Some(Duration::from_millis(500)))
+    ///     .unwrap();
+    /// assert_eq!(result.translation.x, 1.0);
+    /// assert_eq!(result.timestamp, t);
+    /// ```
+    pub fn get_transform_nearest(
+        &self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+        tolerance: Option<Duration>,
+    ) -> Result<Transform<T>, TransformError> {
+        let (from, to) = (self.resolve_frame(from), self.resolve_frame(to));
+        let sample = |buffer: &Buffer<T>, t: &T| {
+            let mut tf = buffer.get_closest(t, tolerance)?;
+            if !tf.timestamp.is_static() {
+                tf.timestamp = *t;
+            }
+            Ok(tf)
+        };
+        Self::process_get_transform(from, to, timestamp, &self.data, &sample)
+    }
+
+    /// Computes the velocity of `to` relative to `from` at `timestamp`.
+    ///
+    /// The transform is looked up at `timestamp - window` and at `timestamp`,
+    /// and the motion between the two is divided by the window, a backward
+    /// finite difference that averages the velocity over the window. The
+    /// result is the body velocity of `to`: the linear velocity of its origin
+    /// and its angular velocity, both expressed in `to`, so the twist's frame
+    /// is `to`. Rotation is differentiated along the shortest arc, so motion
+    /// of more than half a turn within the window is underestimated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TransformError::ZeroVelocityWindow` if `window` is zero,
+    /// `TransformError::TimestampError` if `timestamp - window` underflows,
+    /// and the same errors as [`Registry::get_transform`] if either lookup
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for secs in [1, 3] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(secs as f64, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::from_nanos(secs * 1_000_000_000),
+    ///             parent: "odom".into(),
+    ///             child: "base".into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// let twist = registry
+    ///     .lookup_velocity(
+    ///         "odom",
+    ///         "base",
+    ///         Timestamp::from_nanos(2_500_000_000),
+    ///         Duration::from_secs(1),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(twist.linear, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(twist.frame, "base");
+    /// ```
+    pub fn lookup_velocity(
+        &self,
+        from: &str,
+        to: &str,
+        timestamp: T,
+        window: Duration,
+    ) -> Result<Twist<T>, TransformError> {
+        let to = self.resolve_frame(to);
+        if window.is_zero() {
+            return Err(TransformError::ZeroVelocityWindow);
+        }
+        let start = timestamp.checked_sub(window)?;
+        let before = self.get_transform(from, to, start)?;
+        let after = self.get_transform(from, to, timestamp)?;
+        let dt = window.as_secs_f64();
+
+        let inverse = after.rotation.conjugate();
+        let linear = inverse.rotate_vector(after.translation - before.translation) / dt;
+
+        let rotation_vector = (before.rotation.conjugate() * after.rotation).to_rotation_vector();
+
+        Ok(Twist {
+            linear,
+            angular: rotation_vector / dt,
+            timestamp,
+            frame: to.into(),
+        })
+    }
+
+    /// Retrieves a transform for a specific value into `target_frame`.
+    ///
+    /// The source frame and timestamp are taken from the value.
+    ///
+    /// If the value is already in `target_frame`, this returns an identity
+    /// transform with `parent == child == target_frame` and the value's
+    /// timestamp (via `get_transform`'s same-frame identity).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TransformError` if a transform cannot be resolved.
+    pub fn get_transform_for<U>(
+        &self,
+        value: &U,
+        target_frame: &str,
+    ) -> Result<Transform<T>, TransformError>
+    where
+        U: Localized<T>,
+    {
+        self.get_transform(target_frame, value.frame(), value.timestamp())
+    }
+
+    /// Retrieves a transform between two frames at different timestamps using a fixed frame.
+    ///
+    /// This is the "time travel" API that allows you to get the transform from a source frame
+    /// at one time to a target frame at a different time. This is useful for scenarios like
+    /// tracking an object that was detected on a moving platform (e.g., a conveyor belt) and
+    /// getting its current position in a static world frame.
+    ///
+    /// The algorithm works by:
+    /// 1. Computing the transform that expresses `source_frame` in `fixed_frame` at `source_time`
+    /// 2. Computing the transform that expresses `target_frame` in `fixed_frame` at `target_time`
+    /// 3. Combining the two into the requested transform
+    ///
+    /// `fixed_frame` is a frame that does not change over time, used as an
+    /// intermediate reference point (typically a world or map frame).
+    ///
+    /// Either endpoint may coincide with `fixed_frame`: that leg is then the
+    /// identity, so only the other leg is resolved. When `source_frame` and
+    /// `target_frame` both coincide with it, the result is the identity
+    /// transform carrying `target_time`.
+    ///
+    /// # Choosing the fixed frame
+    ///
+    /// **The caller is responsible for ensuring that `fixed_frame` is actually stationary
+    /// between `source_time` and `target_time`.** Passing a frame that moves between the
+    /// two timestamps will produce a mathematically meaningless result without any error.
+    /// Root frames (e.g., `"world"`, `"map"`) that have no parent are always safe choices.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TransformError` if any of the required transforms cannot be found
+    /// at the specified times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    /// # #[cfg(feature = "std")]
+    /// use core::time::Duration;
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let mut registry = Registry::with_max_age(Duration::from_secs(60));
+    /// # #[cfg(feature = "std")]
+    /// let t1 = Timestamp::now();
+    /// # #[cfg(feature = "std")]
+    /// let t2 = (t1 + Duration::from_secs(1)).unwrap();
+    ///
+    /// # #[cfg(not(feature = "std"))]
+    /// # let mut registry = Registry::new();
+    /// # #[cfg(not(feature = "std"))]
+    /// # let t1 = Timestamp::from_nanos(1_000_000_000);
+    /// # #[cfg(not(feature = "std"))]
+    /// # let t2 = Timestamp::from_nanos(2_000_000_000);
+    ///
+    /// // Tree: fixed -> a -> b
+    ///
+    /// // fixed -> a at t1: a is at x=1
+    /// registry
+    ///     .add_transform(Transform {
+    ///         translation: Vector3::new(1.0, 0.0, 0.0),
+    ///         rotation: Quaternion::identity(),
+    ///         timestamp: t1,
+    ///         parent: "fixed".into(),
+    ///         child: "a".into(),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // fixed -> a at t2: a has moved to x=2
+    /// registry
+    ///     .add_transform(Transform {
+    ///         translation: Vector3::new(2.0, 0.0, 0.0),
+    ///         rotation: Quaternion::identity(),
+    ///         timestamp: t2,
+    ///         parent: "fixed".into(),
+    ///         child: "a".into(),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // a -> b at t1: b is at y=1 relative to a
+    /// registry
+    ///     .add_transform(Transform {
+    ///         translation: Vector3::new(0.0, 1.0, 0.0),
+    ///         rotation: Quaternion::identity(),
+    ///         timestamp: t1,
+    ///         parent: "a".into(),
+    ///         child: "b".into(),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // Express b-at-t1 in a-at-t2, using "fixed" as the stationary reference
+    /// let result = registry.get_transform_at(
+    ///     "a",     // target_frame
+    ///     t2,      // target_time
+    ///     "b",     // source_frame
+    ///     t1,      // source_time
+    ///     "fixed", // fixed_frame
+    /// );
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn get_transform_at(
+        &self,
+        target_frame: &str,
+        target_time: T,
+        source_frame: &str,
+        source_time: T,
+        fixed_frame: &str,
+    ) -> Result<Transform<T>, TransformError> {
+        Self::process_get_transform_at(
+            self.resolve_frame(target_frame),
+            target_time,
+            self.resolve_frame(source_frame),
+            source_time,
+            self.resolve_frame(fixed_frame),
+            &self.data,
+        )
+    }
+
+    /// Removes dynamic transforms older than the given threshold.
+    ///
+    /// Iterates over all buffers and deletes their dynamic entries with a
+    /// timestamp lower than the input argument. Static transforms are
+    /// preserved: they are valid for all time, so cleaning them up by
+    /// timestamp would silently destroy them.
+    ///
+    /// Frames left without any transforms are removed entirely, so the
+    /// registry does not grow without bound as frames come and go.
+    pub fn delete_transforms_before(
+        &mut self,
+        timestamp: T,
+    ) {
+        for buffer in self.data.values_mut() {
+            buffer.delete_before(timestamp);
+        }
+        self.data.retain(|_, buffer| !buffer.is_empty());
+    }
+
+    /// Removes dynamic transforms newer than the given threshold.
+    ///
+    /// The counterpart of [`Registry::delete_transforms_before`], used to
+    /// drop "future" data when a log replay seeks backwards: every buffer
+    /// deletes its dynamic entries with a timestamp greater than the input
+    /// argument. Static transforms are preserved, and frames left without
+    /// any transforms are removed entirely.
+    pub fn delete_transforms_after(
+        &mut self,
+        timestamp: T,
+    ) {
+        for buffer in self.data.values_mut() {
+            buffer.delete_after(timestamp);
+        }
+        self.data.retain(|_, buffer| !buffer.is_empty());
+    }
+
+    /// Removes dynamic transforms within the inclusive range `start..=end`.
+    ///
+    /// Every buffer deletes its dynamic entries stamped inside the range, so
+    /// a corrupted or mis-stamped interval can be removed without wiping
+    /// whole buffers. Nothing is removed if `start` is greater than `end`.
+    /// Static transforms are preserved, and frames left without any
+    /// transforms are removed entirely.
+    pub fn delete_transforms_between(
+        &mut self,
+        start: T,
+        end: T,
+    ) {
+        for buffer in self.data.values_mut() {
+            buffer.delete_between(start, end);
+        }
+        self.data.retain(|_, buffer| !buffer.is_empty());
+    }
+
+    /// Keeps only the transforms for which `keep(parent, child, transform)`
+    /// returns `true`, across all buffers, for custom pruning such as
+    /// dropping every edge of a decommissioned sensor.
+    ///
+    /// Static transforms are visited too. Transforms are visited sorted by
+    /// child frame and then by timestamp, like
+    /// [`Registry::iter_transforms`]. Frames left without any transforms are
+    /// removed entirely, like in [`Registry::delete_transforms_before`];
+    /// their children stay in place and form their own tree until the
+    /// frame is published again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// for child in ["front_lidar", "rear_lidar", "camera"] {
+    ///     registry
+    ///         .add_transform(Transform {
+    ///             translation: Vector3::new(1.0, 0.0, 0.0),
+    ///             rotation: Quaternion::identity(),
+    ///             timestamp: Timestamp::zero(),
+    ///             parent: "base".into(),
+    ///             child: child.into(),
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// registry.retain(|_, child, _| child != "rear_lidar");
+    /// assert!(registry.buffer("rear_lidar").is_none());
+    /// assert!(registry.buffer("front_lidar").is_some());
+    /// ```
+    pub fn retain<F>(
+        &mut self,
+        mut keep: F,
+    ) where
+        F: FnMut(&str, &str, &Transform<T>) -> bool,
+    {
+        let mut buffers: Vec<(&String, &mut Buffer<T>)> = self.data.iter_mut().collect();
+        buffers.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        for (_, buffer) in buffers {
+            buffer.retain(|tf| keep(&tf.parent, &tf.child, tf));
+        }
+        self.data.retain(|_, buffer| !buffer.is_empty());
+    }
+
+    /// Moves a child frame under a new parent, replacing its history.
+    ///
+    /// The transform's `child` frame is detached from its current parent
+    /// and attached to the transform's `parent`, with the transform as its
+    /// first entry. All transforms stored for the old relationship are
+    /// dropped, so stale old-parent entries cannot leak into later lookups.
+    /// Frames below the child move along with it. For a child frame that is
+    /// not yet in the registry this behaves like
+    /// [`Registry::add_transform`].
+    ///
+    /// The operation is atomic: if the new relationship is rejected, the
+    /// registry is left exactly as it was.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BufferError::TransformError` if the transform fails
+    /// validation, `BufferError::SelfReferentialFrame` if its parent and
+    /// child are the same frame, and `BufferError::CycleDetected` if the new
+    /// parent lies below the child in the frame tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     Registry,
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let mut registry = Registry::<Timestamp>::new();
+    /// let t = Timestamp::from_nanos(1_000_000_000);
+    /// let mut transform = Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp: t,
+    ///     parent: "odom".into(),
+    ///     child: "base".into(),
+    /// };
+    /// registry.add_transform(transform.clone()).unwrap();
+    ///
+    /// // Localization converged: attach "base" to "map" instead.
+    /// transform.parent = "map".into();
+    /// registry.reparent(transform).unwrap();
+    ///
+    /// assert!(registry.get_transform("map", "base", t).is_ok());
+    /// assert!(registry.get_transform("odom", "base", t).is_err());
+    /// ```
+    pub fn reparent(
+        &mut self,
+        mut t: Transform<T>,
+    ) -> Result<(), BufferError> {
+        self.remap_transform(&mut t);
+        let config = self.buffer_config(&t.child);
+        let child = t.child.clone();
+        let timestamp = t.timestamp;
+        let previous = self.data.remove(&child);
+        let result = Self::process_add_transform(t, &mut self.data, config, None);
+        match (&result, previous) {
+            (Ok(()), _) => self.note_insert(timestamp),
+            (Err(_), Some(previous)) => {
+                self.data.insert(child, previous);
+            }
+            (Err(_), None) => {}
+        }
+        result
+    }
+
+    /// Removes a frame, its metadata, the remaps onto it, and every edge
+    /// referencing it from the registry.
+    ///
+    /// Drops the frame's own buffer (its edge to its parent) as well as the
+    /// buffers of its direct children (their edges to this frame); the
+    /// children's own subtrees stay in place, now rooted at the children.
+    /// A remapped legacy name removes the frame it resolves to. Returns
+    /// `true` if an edge, metadata or a remap was dropped, so `false` means
+    /// the registry held nothing about the frame. This is also the escape
+    /// hatch for re-parenting, which `add_transform` rejects: remove the
+    /// frame, then re-add it under its new parent.
+    pub fn remove_frame(
+        &mut self,
+        frame: &str,
+    ) -> bool {
+        let frame = String::from(self.resolve_frame(frame));
+        let (edges, remaps) = (self.data.len(), self.remaps.len());
+        self.data
+            .retain(|child, buffer| *child != frame && buffer.parent() != Some(frame.as_str()));
+        self.remaps.retain(|_, canonical| *canonical != frame);
+        let metadata = self.frame_metadata.remove(&frame).is_some();
+        self.data.len() < edges || self.remaps.len() < remaps || metadata
+    }
+
+    /// Renames a frame throughout the registry.
     ///
-    /// **The caller is responsible for ensuring that `fixed_frame` is actually stationary
-    /// between `source_time` and `target_time`.** Passing a frame that moves between the
-    /// two timestamps will produce a mathematically meaningless result without any error.
-    /// Root frames (e.g., `"world"`, `"map"`) that have no parent are always safe choices.
+    /// Updates the frame's own buffer, if any, the parent of every buffer
+    /// below it, including all stored transforms, and the frame's metadata.
+    /// Remaps onto `old` are retargeted to `new`, and a remap of `new` itself
+    /// is dropped, since it now names a frame. Renaming a frame to its
+    /// current name is a no-op. Per-frame retention overrides set with
+    /// [`RegistryBuilder::frame_max_age`] are looked up by name when a
+    /// buffer is created, so an existing buffer keeps its settings.
     ///
     /// # Errors
     ///
-    /// Returns a `TransformError` if any of the required transforms cannot be found
-    /// at the specified times.
+    /// Returns `BufferError::UnknownFrame` if `old` exists nowhere in the
+    /// registry, and `BufferError::FrameExists` if `new` is already in use.
+    /// The registry is unchanged on error.
     ///
     /// # Examples
     ///
@@ -369,116 +2140,203 @@ where
     ///     geometry::{Quaternion, Transform, Vector3},
     ///     time::Timestamp,
     /// };
-    /// # #[cfg(feature = "std")]
-    /// use core::time::Duration;
-    ///
-    /// # #[cfg(feature = "std")]
-    /// let mut registry = Registry::with_max_age(Duration::from_secs(60));
-    /// # #[cfg(feature = "std")]
-    /// let t1 = Timestamp::now();
-    /// # #[cfg(feature = "std")]
-    /// let t2 = (t1 + Duration::from_secs(1)).unwrap();
-    ///
-    /// # #[cfg(not(feature = "std"))]
-    /// # let mut registry = Registry::new();
-    /// # #[cfg(not(feature = "std"))]
-    /// # let t1 = Timestamp::from_nanos(1_000_000_000);
-    /// # #[cfg(not(feature = "std"))]
-    /// # let t2 = Timestamp::from_nanos(2_000_000_000);
-    ///
-    /// // Tree: fixed -> a -> b
     ///
-    /// // fixed -> a at t1: a is at x=1
+    /// let mut registry = Registry::<Timestamp>::new();
     /// registry
     ///     .add_transform(Transform {
-    ///         translation: Vector3::new(1.0, 0.0, 0.0),
+    ///         translation: Vector3::new(0.1, 0.0, 0.2),
     ///         rotation: Quaternion::identity(),
-    ///         timestamp: t1,
-    ///         parent: "fixed".into(),
-    ///         child: "a".into(),
+    ///         timestamp: Timestamp::zero(),
+    ///         parent: "base".into(),
+    ///         child: "calib_tmp".into(),
     ///     })
     ///     .unwrap();
     ///
-    /// // fixed -> a at t2: a has moved to x=2
-    /// registry
-    ///     .add_transform(Transform {
-    ///         translation: Vector3::new(2.0, 0.0, 0.0),
-    ///         rotation: Quaternion::identity(),
-    ///         timestamp: t2,
-    ///         parent: "fixed".into(),
-    ///         child: "a".into(),
-    ///     })
-    ///     .unwrap();
+    /// registry.rename_frame("calib_tmp", "camera").unwrap();
     ///
-    /// // a -> b at t1: b is at y=1 relative to a
-    /// registry
-    ///     .add_transform(Transform {
-    ///         translation: Vector3::new(0.0, 1.0, 0.0),
-    ///         rotation: Quaternion::identity(),
-    ///         timestamp: t1,
-    ///         parent: "a".into(),
-    ///         child: "b".into(),
-    ///     })
+    /// let result = registry
+    ///     .get_transform("base", "camera", Timestamp::zero())
     ///     .unwrap();
-    ///
-    /// // Express b-at-t1 in a-at-t2, using "fixed" as the stationary reference
-    /// let result = registry.get_transform_at(
-    ///     "a",     // target_frame
-    ///     t2,      // target_time
-    ///     "b",     // source_frame
-    ///     t1,      // source_time
-    ///     "fixed", // fixed_frame
-    /// );
-    ///
-    /// assert!(result.is_ok());
+    /// assert_eq!(result.child, "camera");
     /// ```
-    pub fn get_transform_at(
+    pub fn rename_frame(
+        &mut self,
+        old: &str,
+        new: &str,
+    ) -> Result<(), BufferError> {
+        if !Self::frame_exists(old, &self.data) {
+            return Err(BufferError::UnknownFrame(old.into()));
+        }
+        if old == new {
+            return Ok(());
+        }
+        if Self::frame_exists(new, &self.data) {
+            return Err(BufferError::FrameExists(new.into()));
+        }
+
+        if let Some(mut buffer) = self.data.remove(old) {
+            buffer.rename_frame(old, new);
+            self.data.insert(new.into(), buffer);
+        }
+        for buffer in self.data.values_mut() {
+            if buffer.parent() == Some(old) {
+                buffer.rename_frame(old, new);
+            }
+        }
+        if let Some(metadata) = self.frame_metadata.remove(old) {
+            self.frame_metadata
+                .entry_ref(new)
+                .or_default()
+                .extend(metadata);
+        }
+        self.remaps.remove(new);
+        for canonical in self.remaps.values_mut() {
+            if canonical == old {
+                *canonical = new.into();
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces remapped parent and child frames of `t` by their current
+    /// names.
+    fn remap_transform(
         &self,
-        target_frame: &str,
-        target_time: T,
-        source_frame: &str,
-        source_time: T,
-        fixed_frame: &str,
-    ) -> Result<Transform<T>, TransformError> {
-        Self::process_get_transform_at(
-            target_frame,
-            target_time,
-            source_frame,
-            source_time,
-            fixed_frame,
-            &self.data,
-        )
+        t: &mut Transform<T>,
+    ) {
+        if let Some(parent) = self.remaps.get(&t.parent) {
+            t.parent.clone_from(parent);
+        }
+        if let Some(child) = self.remaps.get(&t.child) {
+            t.child.clone_from(child);
+        }
     }
 
-    /// Removes dynamic transforms older than the given threshold.
-    ///
-    /// Iterates over all buffers and deletes their dynamic entries with a
-    /// timestamp lower than the input argument. Static transforms are
-    /// preserved: they are valid for all time, so cleaning them up by
-    /// timestamp would silently destroy them.
-    ///
-    /// Frames left without any transforms are removed entirely, so the
-    /// registry does not grow without bound as frames come and go.
-    pub fn delete_transforms_before(
+    /// Inserts a transform after applying the remap table, replacing the
+    /// dynamic buffers on a time jump, and records `authority` as the writer
+    /// of the child frame.
+    fn insert_with_authority(
+        &mut self,
+        mut t: Transform<T>,
+        authority: Option<&str>,
+    ) -> Result<(), BufferError> {
+        self.remap_transform(&mut t);
+        let config = self.buffer_config(&t.child);
+        let timestamp = t.timestamp;
+        if !self.is_time_jump(timestamp) {
+            Self::process_add_transform(t, &mut self.data, config, authority)?;
+            self.note_insert(timestamp);
+            return Ok(());
+        }
+
+        // Set the dynamic history aside instead of dropping it, so a
+        // rejected insert leaves the registry unchanged.
+        let history: Vec<(String, Buffer<T>)> = self
+            .data
+            .extract_if(|_, buffer| !buffer.is_static())
+            .collect();
+        match Self::process_add_transform(t, &mut self.data, config, authority) {
+            Ok(()) => {
+                self.newest_dynamic = Some(timestamp);
+                Ok(())
+            }
+            Err(error) => {
+                self.data.extend(history);
+                Err(error)
+            }
+        }
+    }
+
+    /// Raises the newest dynamic timestamp after a successful insert at
+    /// `timestamp`.
+    fn note_insert(
         &mut self,
         timestamp: T,
     ) {
-        for buffer in self.data.values_mut() {
-            buffer.delete_before(timestamp);
+        if !timestamp.is_static() {
+            self.newest_dynamic = self.newest_dynamic.max(Some(timestamp));
         }
-        self.data.retain(|_, buffer| !buffer.is_empty());
     }
 
-    /// Removes a child frame and all of its transforms from the registry.
+    /// Returns `true` if a dynamic transform at `timestamp` lies further
+    /// behind the newest dynamic timestamp than the time-jump threshold.
     ///
-    /// Returns `true` if the frame existed. This is also the escape hatch
-    /// for re-parenting, which `add_transform` rejects: remove the frame,
-    /// then re-add it under its new parent.
-    pub fn remove_frame(
+    /// Checks the running newest timestamp first; only when that suggests a
+    /// jump are the buffers scanned, which also refreshes it.
+    fn is_time_jump(
         &mut self,
-        child: &str,
+        timestamp: T,
     ) -> bool {
-        self.data.remove(child).is_some()
+        let Some(threshold) = self.time_jump_threshold else {
+            return false;
+        };
+        if timestamp.is_static() {
+            return false;
+        }
+        let jumps_back = |newest: Option<T>| {
+            newest
+                .and_then(|newest| newest.duration_since(timestamp).ok())
+                .is_some_and(|behind| behind > threshold)
+        };
+        if !jumps_back(self.newest_dynamic) {
+            return false;
+        }
+        self.newest_dynamic = self
+            .data
+            .values()
+            .filter(|buffer| !buffer.is_static())
+            .filter_map(Buffer::newest)
+            .max();
+        jumps_back(self.newest_dynamic)
+    }
+
+    /// Samples every edge on the path between the resolved frames `from`
+    /// and `to` with `sample`, ordered along the path.
+    fn sample_path<R>(
+        &self,
+        from: &str,
+        to: &str,
+        sample: &dyn Fn(&Buffer<T>) -> Result<R, BufferError>,
+    ) -> Result<Vec<R>, TransformError> {
+        let Some((up, down)) = Self::split_path(from, to, &self.data) else {
+            return Err(Self::diagnose_not_found(from, to, &self.data, &mut None));
+        };
+        let edges = up
+            .split_last()
+            .map_or(&[][..], |(_, below)| below)
+            .iter()
+            .chain(
+                down.split_last()
+                    .map_or(&[][..], |(_, below)| below)
+                    .iter()
+                    .rev(),
+            );
+
+        edges
+            .map(|&child| {
+                let buffer = self
+                    .data
+                    .get(child)
+                    .ok_or_else(|| TransformError::Disconnected(from.into(), to.into()))?;
+                sample(buffer).map_err(|source| TransformError::NotFoundAt {
+                    from: from.into(),
+                    to: to.into(),
+                    frame: child.into(),
+                    source: Box::new(source),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the retention settings for a new buffer of `child`.
+    fn buffer_config(
+        &self,
+        child: &str,
+    ) -> BufferConfig {
+        match self.frame_max_age.get(child) {
+            Some(&max_age) => self.config.with_max_age(max_age),
+            None => self.config,
+        }
     }
 
     /// Adds a transform to the data buffer.
@@ -490,7 +2348,8 @@ where
     fn process_add_transform(
         t: Transform<T>,
         data: &mut HashMap<String, Buffer<T>>,
-        max_age: Option<Duration>,
+        config: BufferConfig,
+        authority: Option<&str>,
     ) -> Result<(), BufferError> {
         // A new child->parent relationship changes the tree topology; reject
         // it if it would close a cycle. (Existing buffers have their parent
@@ -500,19 +2359,19 @@ where
         }
 
         if let Some(buffer) = data.get_mut(&t.child) {
-            return buffer.insert(t);
+            buffer.insert(t)?;
+            buffer.set_authority(authority);
+            return Ok(());
         }
 
         // New frame: fill the buffer BEFORE registering it in the map, so a
         // failed insert cannot leave an empty, parentless frame behind —
         // which would bypass the cycle check on a later insert of the same
         // child frame.
-        let mut buffer = match max_age {
-            Some(max_age) => Buffer::with_max_age(max_age),
-            None => Buffer::new(),
-        };
+        let mut buffer = Buffer::with_config(config);
         let child = t.child.clone();
         buffer.insert(t)?;
+        buffer.set_authority(authority);
         data.insert(child, buffer);
         Ok(())
     }
@@ -526,11 +2385,11 @@ where
     fn creates_cycle(
         child: &str,
         parent: &str,
-        data: &HashMap<String, Buffer<T>>,
+        data: &impl BufferMap<T>,
     ) -> bool {
         let mut visited = BTreeSet::new();
         let mut current = parent;
-        while let Some(buffer) = data.get(current) {
+        while let Some(buffer) = data.buffer(current) {
             if !visited.insert(current) {
                 return true;
             }
@@ -547,14 +2406,141 @@ where
         false
     }
 
+    /// Returns the dynamic buffers on the path between two frames: the edges
+    /// below their common ancestor on either side. Empty if the frames are
+    /// not connected.
+    fn dynamic_path_buffers<'a>(
+        from: &'a str,
+        to: &'a str,
+        data: &'a impl BufferMap<T>,
+    ) -> Vec<&'a Buffer<T>>
+    where
+        T: 'a,
+    {
+        let Some((up, down)) = Self::split_path(from, to, data) else {
+            return Vec::new();
+        };
+        // Each path ends at the common ancestor, whose own edge is not on it.
+        let below_common = |path: Vec<&'a str>| {
+            let len = path.len().saturating_sub(1);
+            path.into_iter().take(len)
+        };
+
+        below_common(up)
+            .chain(below_common(down))
+            .filter_map(|frame| data.buffer(frame))
+            .filter(|buffer| !buffer.is_static())
+            .collect()
+    }
+
+    /// Splits the path between two frames at their closest common ancestor:
+    /// `from` and its ancestors up to and including the common one, and the
+    /// same for `to`. `None` if the frames share no tree.
+    fn split_path<'a>(
+        from: &'a str,
+        to: &'a str,
+        data: &'a impl BufferMap<T>,
+    ) -> Option<(Vec<&'a str>, Vec<&'a str>)>
+    where
+        T: 'a,
+    {
+        let common = Self::common_ancestor(from, to, data)?;
+        let through_common = |frame| {
+            let mut path = Self::ancestors_of(frame, data);
+            if let Some(index) = path.iter().position(|&ancestor| ancestor == common) {
+                path.truncate(index + 1);
+            }
+            path
+        };
+        Some((through_common(from), through_common(to)))
+    }
+
+    /// Returns the closest common ancestor of two frames, where a frame
+    /// counts as its own ancestor, or `None` if they share no tree.
+    ///
+    /// Climbs the deeper frame to the depth of the other, then both in
+    /// lockstep: linear in the depth of the frames, without sampling any
+    /// buffer or allocating.
+    fn common_ancestor<'a>(
+        a: &'a str,
+        b: &'a str,
+        data: &'a impl BufferMap<T>,
+    ) -> Option<&'a str>
+    where
+        T: 'a,
+    {
+        let depth = |frame: &str| {
+            let mut depth = 0;
+            let mut current = Self::parent_of(frame, data);
+            // Acyclic by construction; the bound is a defensive backstop.
+            while let Some(parent) = current {
+                if depth > data.len() {
+                    break;
+                }
+                depth += 1;
+                current = Self::parent_of(parent, data);
+            }
+            depth
+        };
+
+        let (mut a, mut b) = (a, b);
+        let (mut depth_a, mut depth_b) = (depth(a), depth(b));
+        while depth_a > depth_b {
+            a = Self::parent_of(a, data)?;
+            depth_a -= 1;
+        }
+        while depth_b > depth_a {
+            b = Self::parent_of(b, data)?;
+            depth_b -= 1;
+        }
+        while a != b {
+            a = Self::parent_of(a, data)?;
+            b = Self::parent_of(b, data)?;
+        }
+        Some(a)
+    }
+
+    /// Returns the parent frame of `frame`, if it has one.
+    fn parent_of<'a>(
+        frame: &str,
+        data: &'a impl BufferMap<T>,
+    ) -> Option<&'a str>
+    where
+        T: 'a,
+    {
+        data.buffer(frame).and_then(Buffer::parent)
+    }
+
+    /// Returns `frame` followed by its ancestors, up to and including the
+    /// root of its tree.
+    fn ancestors_of<'a>(
+        frame: &'a str,
+        data: &'a impl BufferMap<T>,
+    ) -> Vec<&'a str>
+    where
+        T: 'a,
+    {
+        let mut ancestors = vec![frame];
+        let mut current = frame;
+        // Acyclic by construction; the length bound is a defensive backstop.
+        while let Some(parent) = data.buffer(current).and_then(Buffer::parent) {
+            if ancestors.len() > data.len() {
+                break;
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors
+    }
+
     /// Returns `true` if the frame appears anywhere in the tree, as a child
     /// (buffer key) or as a parent. Roots exist only as parents, so a
     /// missing buffer alone does not make a frame unknown.
     fn frame_exists(
         frame: &str,
-        data: &HashMap<String, Buffer<T>>,
+        data: &impl BufferMap<T>,
     ) -> bool {
-        data.contains_key(frame) || data.values().any(|buffer| buffer.parent() == Some(frame))
+        data.buffer(frame).is_some() || data.buffers().any(|buffer| buffer.parent() == Some(frame))
     }
 
     /// Diagnoses a failed lookup, in order of certainty: a requested frame
@@ -565,7 +2551,7 @@ where
     fn diagnose_not_found(
         from: &str,
         to: &str,
-        data: &HashMap<String, Buffer<T>>,
+        data: &impl BufferMap<T>,
         walk_failure: &mut Option<(String, BufferError)>,
     ) -> TransformError {
         for frame in [from, to] {
@@ -586,6 +2572,9 @@ where
 
     /// Retrieves and computes the transform between two frames at a specific timestamp.
     ///
+    /// `sample` reads one buffer at the requested time: [`Buffer::get`] for
+    /// interpolating lookups, [`Buffer::get_exact`] for exact ones.
+    ///
     /// # Errors
     ///
     /// * `TransformError::UnknownFrame` - If a requested frame exists nowhere in the tree
@@ -593,12 +2582,16 @@ where
     ///   but could not serve the requested time
     /// * `TransformError::Disconnected` - If both frames exist but no chain connects them
     /// * Other variants of `TransformError` resulting from transform operations
-    fn process_get_transform(
+    fn process_get_transform<F>(
         from: &str,
         to: &str,
         timestamp: T,
-        data: &HashMap<String, Buffer<T>>,
-    ) -> Result<Transform<T>, TransformError> {
+        data: &impl BufferMap<T>,
+        sample: &F,
+    ) -> Result<Transform<T>, TransformError>
+    where
+        F: Fn(&Buffer<T>, &T) -> Result<Transform<T>, BufferError>,
+    {
         // A frame relative to itself is the identity, regardless of whether
         // the frame is known: the answer holds either way, and it keeps
         // same-frame queries consistent with `get_transform_for`.
@@ -616,8 +2609,21 @@ where
             chain.back().is_some_and(|tf| tf.parent == target)
         };
 
+        // Both walks stop at the closest common ancestor, so edges above it
+        // are never sampled. Without one, the walks run to their roots and
+        // the failure is diagnosed below. If either frame is a root, the
+        // walks cannot pass the common ancestor anyway and the search is
+        // skipped.
+        let common = if data.buffer(from).is_some() && data.buffer(to).is_some() {
+            Self::common_ancestor(from, to, data)
+        } else {
+            None
+        };
+        let (from_until, to_until) = (common.unwrap_or(to), common.unwrap_or(from));
+
         let mut walk_failure = None;
-        let from_chain = Self::get_transform_chain(from, to, timestamp, data, &mut walk_failure);
+        let from_chain =
+            Self::get_transform_chain(from, from_until, timestamp, data, sample, &mut walk_failure);
 
         let result = match from_chain {
             // `to` is an ancestor of `from`: the from-side chain spans the
@@ -627,7 +2633,7 @@ where
             }
             from_chain => match (
                 from_chain,
-                Self::get_transform_chain(to, from, timestamp, data, &mut walk_failure),
+                Self::get_transform_chain(to, to_until, timestamp, data, sample, &mut walk_failure),
             ) {
                 // `from` is an ancestor of `to`: the to-side chain spans the
                 // whole path by itself.
@@ -704,7 +2710,7 @@ where
         source_frame: &str,
         source_time: T,
         fixed_frame: &str,
-        data: &HashMap<String, Buffer<T>>,
+        data: &impl BufferMap<T>,
     ) -> Result<Transform<T>, TransformError> {
         // Following tf2's algorithm:
         // 1. Get transform expressing source_frame in fixed_frame at source_time
@@ -731,27 +2737,47 @@ where
         }
         if source_frame == fixed_frame {
             // The answer is the target leg alone, inverted.
-            let mut result =
-                Self::process_get_transform(fixed_frame, target_frame, target_time, data)?
-                    .inverse()?;
+            let mut result = Self::process_get_transform(
+                fixed_frame,
+                target_frame,
+                target_time,
+                data,
+                &Buffer::get,
+            )?
+            .inverse()?;
             result.timestamp = target_time;
             return Ok(result);
         }
         if target_frame == fixed_frame {
             // The answer is the source leg alone.
-            let mut result =
-                Self::process_get_transform(fixed_frame, source_frame, source_time, data)?;
+            let mut result = Self::process_get_transform(
+                fixed_frame,
+                source_frame,
+                source_time,
+                data,
+                &Buffer::get,
+            )?;
             result.timestamp = target_time;
             return Ok(result);
         }
 
         // Step 1: Get transform expressing source_frame in fixed_frame at source_time
-        let mut source_to_fixed =
-            Self::process_get_transform(fixed_frame, source_frame, source_time, data)?;
+        let mut source_to_fixed = Self::process_get_transform(
+            fixed_frame,
+            source_frame,
+            source_time,
+            data,
+            &Buffer::get,
+        )?;
 
         // Step 2: Get transform expressing target_frame in fixed_frame at target_time
-        let mut target_to_fixed =
-            Self::process_get_transform(fixed_frame, target_frame, target_time, data)?;
+        let mut target_to_fixed = Self::process_get_transform(
+            fixed_frame,
+            target_frame,
+            target_time,
+            data,
+            &Buffer::get,
+        )?;
 
         // Since both transforms are expressed relative to a fixed frame, we can simply multiply them
         // with their timestamps set to the static value.
@@ -765,52 +2791,58 @@ where
         Ok(result)
     }
 
-    /// Constructs a chain of transforms from a starting frame to a target
-    /// frame at a given timestamp, or `None` if the walk yields no
-    /// transforms. Diagnosing the reason is the caller's job
+    /// Constructs a chain of transforms from a starting frame up to the
+    /// `stop` frame (or the root) at a given timestamp, or `None` if the
+    /// walk yields no transforms. Diagnosing the reason is the caller's job
     /// (`diagnose_not_found`).
     ///
     /// A buffer lookup failing along the way ends the walk; the first such
     /// failure across all walks of one lookup is recorded in `walk_failure`
     /// so the caller can report it if the lookup fails as a whole.
-    fn get_transform_chain(
+    fn get_transform_chain<F>(
         from: &str,
-        to: &str,
+        stop: &str,
         timestamp: T,
-        data: &HashMap<String, Buffer<T>>,
+        data: &impl BufferMap<T>,
+        sample: &F,
         walk_failure: &mut Option<(String, BufferError)>,
-    ) -> Option<VecDeque<Transform<T>>> {
+    ) -> Option<VecDeque<Transform<T>>>
+    where
+        F: Fn(&Buffer<T>, &T) -> Result<Transform<T>, BufferError>,
+    {
         let mut transforms = VecDeque::new();
-        let mut current_frame: String = from.into();
+        // Walk on the frame names pinned in the buffers, so no hop allocates.
+        let mut current_frame = from;
 
         // The frame tree is acyclic by construction (cycles are rejected at
         // insertion), so the walk terminates at a root; the depth bound is a
         // defensive backstop only.
         let mut remaining = data.len();
-        while let Some(frame_buffer) = data.get(&current_frame) {
+        while let Some(frame_buffer) = data.buffer(current_frame) {
+            // Reaching `stop` completes the chain; walking on to the root
+            // would only sample edges that truncate_at_common_parent
+            // discards again.
+            if current_frame == stop {
+                break;
+            }
             if remaining == 0 {
                 return None;
             }
             remaining -= 1;
 
-            match frame_buffer.get(&timestamp) {
-                Ok(tf) => {
-                    current_frame.clone_from(&tf.parent);
+            match (sample(frame_buffer, &timestamp), frame_buffer.parent()) {
+                (Ok(tf), Some(parent)) => {
+                    current_frame = parent;
                     transforms.push_back(tf);
                 }
-                Err(source) => {
+                (Ok(_), None) => break,
+                (Err(source), _) => {
                     if walk_failure.is_none() {
-                        *walk_failure = Some((current_frame.clone(), source));
+                        *walk_failure = Some((current_frame.into(), source));
                     }
                     break;
                 }
             }
-
-            // Reaching `to` completes the chain; walking on to the root would
-            // only add work that truncate_at_common_parent discards again.
-            if current_frame == to {
-                break;
-            }
         }
 
         if transforms.is_empty() {
@@ -896,5 +2928,101 @@ where
     }
 }
 
+impl<T> fmt::Debug for Registry<T>
+where
+    T: TimePoint + fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        // Sorted copies keep the output independent of the hash seed.
+        f.debug_struct("Registry")
+            .field("data", &sorted(&self.data))
+            .field("config", &self.config)
+            .field("frame_max_age", &sorted(&self.frame_max_age))
+            .field("time_jump_threshold", &self.time_jump_threshold)
+            .field("newest_dynamic", &self.newest_dynamic)
+            .field("frame_metadata", &sorted(&self.frame_metadata))
+            .field("remaps", &sorted(&self.remaps))
+            .finish()
+    }
+}
+
+/// Read access to buffers keyed by child frame, shared by the chain
+/// resolution helpers: the registry's own map, or the merged view of a
+/// [`RegistryGroup`].
+trait BufferMap<T>
+where
+    T: TimePoint,
+{
+    /// Returns the buffer of `child`, if any.
+    fn buffer(
+        &self,
+        child: &str,
+    ) -> Option<&Buffer<T>>;
+
+    /// Returns every buffer, in no particular order.
+    fn buffers<'a>(&'a self) -> impl Iterator<Item = &'a Buffer<T>>
+    where
+        T: 'a;
+
+    /// Returns the number of buffers.
+    fn len(&self) -> usize;
+}
+
+impl<T> BufferMap<T> for HashMap<String, Buffer<T>>
+where
+    T: TimePoint,
+{
+    fn buffer(
+        &self,
+        child: &str,
+    ) -> Option<&Buffer<T>> {
+        self.get(child)
+    }
+
+    fn buffers<'a>(&'a self) -> impl Iterator<Item = &'a Buffer<T>>
+    where
+        T: 'a,
+    {
+        self.values()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> BufferMap<T> for HashMap<&str, &Buffer<T>>
+where
+    T: TimePoint,
+{
+    fn buffer(
+        &self,
+        child: &str,
+    ) -> Option<&Buffer<T>> {
+        self.get(child).copied()
+    }
+
+    fn buffers<'a>(&'a self) -> impl Iterator<Item = &'a Buffer<T>>
+    where
+        T: 'a,
+    {
+        self.values().copied()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Borrows the entries of `map` sorted by key.
+fn sorted<V>(map: &HashMap<String, V>) -> BTreeMap<&str, &V> {
+    map.iter()
+        .map(|(key, value)| (key.as_str(), value))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,160 @@
+use alloc::string::String;
+use core::fmt;
+use hashbrown::HashMap;
+
+use crate::{
+    core::Buffer,
+    errors::TransformError,
+    geometry::Transform,
+    time::{TimePoint, Timestamp},
+};
+
+use super::{Registry, sorted};
+
+/// An immutable view of a [`Registry`] frozen at one instant.
+///
+/// Created by [`Registry::freeze_at`], a snapshot holds the resolved
+/// transform of every edge at its timestamp, so a pipeline stage can run
+/// deterministic per-frame processing without holding on to the live
+/// registry. Edges whose buffer could not serve the timestamp are left out,
+/// so a lookup across one fails with `TransformError::UnknownFrame` or
+/// `TransformError::Disconnected` where the registry would report the
+/// missing sample as `TransformError::NotFoundAt`. Frame names are resolved
+/// through the registry's remap table at freezing time.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Registry,
+///     geometry::{Quaternion, Transform, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let mut registry = Registry::<Timestamp>::new();
+/// let t = Timestamp::from_nanos(1_000_000_000);
+/// registry
+///     .add_transform(Transform {
+///         translation: Vector3::new(1.0, 0.0, 0.0),
+///         rotation: Quaternion::identity(),
+///         timestamp: t,
+///         parent: "map".into(),
+///         child: "base".into(),
+///     })
+///     .unwrap();
+///
+/// let snapshot = registry.freeze_at(t);
+/// drop(registry);
+///
+/// let result = snapshot.get_transform("base", "map").unwrap();
+/// assert_eq!(result.translation, Vector3::new(-1.0, 0.0, 0.0));
+/// ```
+pub struct Snapshot<T = Timestamp>
+where
+    T: TimePoint,
+{
+    timestamp: T,
+    /// Single-entry buffers, keyed by child frame like the registry's.
+    data: HashMap<String, Buffer<T>>,
+    /// The registry's remap table when the snapshot was taken.
+    remaps: HashMap<String, String>,
+}
+
+impl<T> Snapshot<T>
+where
+    T: TimePoint,
+{
+    /// Resolves every edge of `data` at `timestamp`, keeping `remaps` for
+    /// lookups.
+    pub(super) fn new(
+        timestamp: T,
+        data: &HashMap<String, Buffer<T>>,
+        remaps: HashMap<String, String>,
+    ) -> Self {
+        let data = data
+            .iter()
+            .filter_map(|(child, buffer)| {
+                let mut frozen = Buffer::new();
+                frozen.insert(buffer.get(&timestamp).ok()?).ok()?;
+                Some((child.clone(), frozen))
+            })
+            .collect();
+        Self {
+            timestamp,
+            data,
+            remaps,
+        }
+    }
+
+    /// Returns the timestamp the snapshot was taken at.
+    #[must_use]
+    pub fn timestamp(&self) -> T {
+        self.timestamp
+    }
+
+    /// Returns the number of edges in the snapshot.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no edge could be resolved at the timestamp.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the resolved transform into `child` from its parent, if the
+    /// edge is part of the snapshot.
+    #[must_use]
+    pub fn edge(
+        &self,
+        child: &str,
+    ) -> Option<&Transform<T>> {
+        self.data.get(child)?.entries().next()
+    }
+
+    /// Returns an iterator over the resolved transform of every edge,
+    /// sorted by child frame.
+    pub fn edges(&self) -> impl Iterator<Item = &Transform<T>> {
+        sorted(&self.data).into_values().flat_map(Buffer::entries)
+    }
+
+    /// Retrieves the transform from the `from` frame to the `to` frame at
+    /// the snapshot's timestamp.
+    ///
+    /// Legacy frame names are resolved like in [`Registry::get_transform`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Registry::get_transform`] for a registry
+    /// holding only the snapshot's edges. An edge left out at freezing time
+    /// is missing from the tree, so it surfaces as
+    /// `TransformError::UnknownFrame` or `TransformError::Disconnected`
+    /// rather than `TransformError::NotFoundAt`.
+    pub fn get_transform(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Transform<T>, TransformError> {
+        let resolve = |frame| self.remaps.get(frame).map_or(frame, String::as_str);
+        let (from, to) = (resolve(from), resolve(to));
+        Registry::process_get_transform(from, to, self.timestamp, &self.data, &Buffer::get)
+    }
+}
+
+impl<T> fmt::Debug for Snapshot<T>
+where
+    T: TimePoint + fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("timestamp", &self.timestamp)
+            .field("data", &sorted(&self.data))
+            .field("remaps", &sorted(&self.remaps))
+            .finish()
+    }
+}
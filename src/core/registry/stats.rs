@@ -0,0 +1,86 @@
+use alloc::{string::String, vec::Vec};
+use core::{mem::size_of, time::Duration};
+
+use crate::{
+    core::Buffer,
+    geometry::Transform,
+    time::{TimePoint, Timestamp},
+};
+
+/// Memory and size statistics of a [`Registry`](super::Registry), returned
+/// by [`Registry::stats`](super::Registry::stats).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RegistryStats<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// Per-frame statistics, sorted by child frame.
+    pub frames: Vec<FrameStats<T>>,
+    /// The number of transforms stored across all frames.
+    pub total_transforms: usize,
+    /// The sum of the frames' estimated bytes.
+    pub estimated_bytes: usize,
+}
+
+/// Statistics of a single child frame's buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FrameStats<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The child frame owning the buffer.
+    pub child: String,
+    /// The parent frame of the buffer's transforms.
+    pub parent: String,
+    /// `true` if the buffer holds a static transform.
+    pub is_static: bool,
+    /// The number of stored transforms.
+    pub len: usize,
+    /// The timestamp of the oldest stored transform.
+    pub oldest: Option<T>,
+    /// The timestamp of the newest stored transform.
+    pub newest: Option<T>,
+    /// The authority that wrote the newest insert, if it was given one.
+    pub authority: Option<String>,
+    /// The smoothed interval between incoming samples, see
+    /// [`Buffer::mean_interval`].
+    pub mean_interval: Option<Duration>,
+    /// An estimate of the memory held by the stored transforms: each entry's
+    /// key, value and frame-name heap bytes. Allocator and tree-node
+    /// overhead are not included, so the real usage is somewhat higher.
+    pub estimated_bytes: usize,
+}
+
+impl<T> FrameStats<T>
+where
+    T: TimePoint,
+{
+    /// Collects the statistics of `buffer`.
+    pub(super) fn new(
+        child: &str,
+        buffer: &Buffer<T>,
+    ) -> Self {
+        let estimated_bytes = buffer
+            .entries()
+            .map(|tf| {
+                size_of::<T>()
+                    + size_of::<Transform<T>>()
+                    + tf.parent.capacity()
+                    + tf.child.capacity()
+            })
+            .sum();
+        Self {
+            child: child.into(),
+            parent: buffer.parent().unwrap_or_default().into(),
+            is_static: buffer.is_static(),
+            len: buffer.len(),
+            oldest: buffer.oldest(),
+            newest: buffer.newest(),
+            authority: buffer.authority().map(Into::into),
+            mean_interval: buffer.mean_interval(),
+            estimated_bytes,
+        }
+    }
+}
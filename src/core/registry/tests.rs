@@ -2,12 +2,17 @@
 mod registry_tests {
     use crate::{
         Registry, Transformable,
+        core::{
+            Buffer, BufferConfig, EdgeDiff, InterpolationPolicy, LookupCache, RegistryGroup,
+            TreeIssue,
+        },
         errors::{BufferError, TransformError},
         geometry::{Point, Quaternion, Transform, Vector3},
         time::Timestamp,
     };
+    use alloc::{format, string::ToString, vec, vec::Vec};
     use approx::assert_abs_diff_eq;
-    use core::time::Duration;
+    use core::{num::NonZeroUsize, time::Duration};
 
     #[test]
     fn basic_chain_linear() {
@@ -107,7 +112,7 @@ mod registry_tests {
         let theta = core::f64::consts::PI / 2.0;
         let t_b_c = Transform {
             translation: Vector3::new(0.0, 0.0, 0.0),
-            rotation: Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin()),
+            rotation: Quaternion::from_rotation_z(theta),
             timestamp: t,
             parent: "b".into(),
             child: "c".into(),
@@ -128,7 +133,7 @@ mod registry_tests {
 
         let t_a_d = Transform {
             translation: Vector3::new(1.0, 1.0, 0.0),
-            rotation: Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin()),
+            rotation: Quaternion::from_rotation_z(theta),
             timestamp: t,
             parent: "a".into(),
             child: "d".into(),
@@ -157,7 +162,7 @@ mod registry_tests {
         let theta = core::f64::consts::PI / 2.0;
         let t_a_c = Transform {
             translation: Vector3::new(0.0, 1.0, 0.0),
-            rotation: Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin()),
+            rotation: Quaternion::from_rotation_z(theta),
             timestamp: t,
             parent: "a".into(),
             child: "c".into(),
@@ -195,7 +200,7 @@ mod registry_tests {
         let theta = core::f64::consts::PI / 2.0;
         let t_a_b_1 = Transform {
             translation: Vector3::new(0.0, 1.0, 0.0),
-            rotation: Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin()),
+            rotation: Quaternion::from_rotation_z(theta),
             timestamp: (t + Duration::from_secs(1)).unwrap(),
             parent: "a".into(),
             child: "b".into(),
@@ -372,10 +377,22 @@ mod registry_tests {
         registry.add_transform(t_b_d).unwrap();
 
         let mut walk_failure = None;
-        let from_chain =
-            Registry::get_transform_chain("d", "a", t, &registry.data, &mut walk_failure);
-        let mut to_chain =
-            Registry::get_transform_chain("c", "a", t, &registry.data, &mut walk_failure);
+        let from_chain = Registry::get_transform_chain(
+            "d",
+            "a",
+            t,
+            &registry.data,
+            &Buffer::get,
+            &mut walk_failure,
+        );
+        let mut to_chain = Registry::get_transform_chain(
+            "c",
+            "a",
+            t,
+            &registry.data,
+            &Buffer::get,
+            &mut walk_failure,
+        );
 
         if let Some(chain) = to_chain.as_mut() {
             Registry::reverse_and_invert_transforms(chain).expect("failed to reverse and invert");
@@ -559,7 +576,7 @@ mod registry_tests {
         registry
             .add_transform(Transform {
                 translation: Vector3::new(0.0, 0.0, 0.0),
-                rotation: Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin()),
+                rotation: Quaternion::from_rotation_z(theta),
                 timestamp: t2,
                 parent: "fixed".into(),
                 child: "a".into(),
@@ -1091,210 +1108,1864 @@ mod registry_tests {
     }
 
     #[test]
-    fn delete_transforms_before_prunes_empty_frames() {
+    fn reparent_replaces_history_and_keeps_subtree() {
         let mut registry = Registry::new();
         let t1 = Timestamp::from_nanos(1_000_000_000);
-        let t2 = Timestamp::from_nanos(3_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
 
+        for &t in &[t1, t2] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "odom".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
         registry
             .add_transform(Transform {
-                translation: Vector3::new(1.0, 0.0, 0.0),
+                translation: Vector3::new(0.5, 0.0, 0.0),
                 rotation: Quaternion::identity(),
-                timestamp: t1,
-                parent: "world".into(),
-                child: "object".into(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
             })
             .unwrap();
 
-        // Wiping every transform of a frame releases the frame itself, so
-        // the registry does not accumulate dead frames — and the frame can
-        // come back under a new parent.
-        registry.delete_transforms_before(t2);
         registry
-            .add_transform(Transform {
-                translation: Vector3::new(0.0, 0.5, 0.0),
+            .reparent(Transform {
+                translation: Vector3::new(3.0, 0.0, 0.0),
                 rotation: Quaternion::identity(),
                 timestamp: t2,
-                parent: "gripper".into(),
-                child: "object".into(),
+                parent: "map".into(),
+                child: "base".into(),
             })
             .unwrap();
-        assert!(registry.get_transform("gripper", "object", t2).is_ok());
+
+        assert!(registry.get_transform("odom", "base", t2).is_err());
+        // Only the new entry remains: t1 is no longer covered.
+        assert!(registry.get_transform("map", "base", t1).is_err());
+        assert_eq!(
+            registry
+                .get_transform("map", "lidar", t2)
+                .unwrap()
+                .translation,
+            Vector3::new(3.5, 0.0, 0.0)
+        );
     }
 
     #[test]
-    fn get_transform_unknown_frame_returns_not_found() {
+    fn reparent_rejecting_a_cycle_leaves_the_registry_unchanged() {
         let mut registry = Registry::new();
         let t = Timestamp::from_nanos(1_000_000_000);
 
-        registry
-            .add_transform(Transform {
-                translation: Vector3::new(1.0, 0.0, 0.0),
-                rotation: Quaternion::identity(),
-                timestamp: t,
-                parent: "a".into(),
-                child: "b".into(),
-            })
-            .unwrap();
+        for (parent, child) in [("map", "base"), ("base", "lidar")] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
 
-        // The requested frame does not exist. The walk from "b" still resolves
-        // up to the root "a", but that partial answer must not be returned as
-        // if it were the requested transform; the error names the unknown
-        // frame.
-        let result = registry.get_transform("b", "does_not_exist", t);
-        assert!(
-            matches!(&result, Err(TransformError::UnknownFrame(frame)) if frame == "does_not_exist"),
-            "expected UnknownFrame for unknown target frame, got {result:?}"
-        );
+        let result = registry.reparent(Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: t,
+            parent: "lidar".into(),
+            child: "base".into(),
+        });
 
-        let result = registry.get_transform("does_not_exist", "b", t);
-        assert!(
-            matches!(&result, Err(TransformError::UnknownFrame(frame)) if frame == "does_not_exist"),
-            "expected UnknownFrame for unknown source frame, got {result:?}"
+        assert!(matches!(result, Err(BufferError::CycleDetected)));
+        assert_eq!(
+            registry
+                .get_transform("map", "lidar", t)
+                .unwrap()
+                .translation,
+            Vector3::new(2.0, 0.0, 0.0)
         );
     }
 
     #[test]
-    // The compared seconds are exactly representable; the assertion is on
-    // the reported values, not on float arithmetic.
-    #[allow(clippy::float_cmp)]
-    fn get_transform_partial_chain_reports_failing_frame() {
+    fn freeze_at_resolves_every_edge_at_the_instant() {
         let mut registry = Registry::new();
-        let t0 = Timestamp::from_nanos(1_000_000_000);
-
-        let t1 = (t0 + Duration::from_secs(1)).unwrap();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+        let query = Timestamp::from_nanos(2_000_000_000);
 
-        // a -> b is only known at t0; b -> c is known at t0 and t1.
         registry
             .add_transform(Transform {
-                translation: Vector3::new(1.0, 0.0, 0.0),
+                translation: Vector3::new(0.5, 0.0, 0.0),
                 rotation: Quaternion::identity(),
-                timestamp: t0,
-                parent: "a".into(),
-                child: "b".into(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
             })
             .unwrap();
-        for &t in &[t0, t1] {
+        for (t, x) in [(t1, 1.0), (t2, 3.0)] {
             registry
                 .add_transform(Transform {
-                    translation: Vector3::new(0.0, 1.0, 0.0),
+                    translation: Vector3::new(x, 0.0, 0.0),
                     rotation: Quaternion::identity(),
                     timestamp: t,
-                    parent: "b".into(),
-                    child: "c".into(),
+                    parent: "map".into(),
+                    child: "base".into(),
                 })
                 .unwrap();
         }
+        // Only covers t2, so it cannot be resolved at the query time.
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t2,
+                parent: "map".into(),
+                child: "object".into(),
+            })
+            .unwrap();
 
-        // At t1 only the c -> b hop can be resolved; the chain to "a" is
-        // incomplete and must not be returned as a c -> a transform. The
-        // error pinpoints "b" as the frame that could not serve t1 and
-        // carries the covered range as the cause: b's data ends at t0
-        // (1.0s), one second before the requested t1 (2.0s).
-        let result = registry.get_transform("c", "a", t1);
-        assert!(
-            matches!(
-                &result,
-                Err(TransformError::NotFoundAt { frame, source, .. })
-                    if frame == "b"
-                        && matches!(
-                            source.as_ref(),
-                            BufferError::TransformError(TransformError::TimestampOutOfRange(
-                                requested,
-                                start,
-                                end
-                            )) if *requested == 2.0 && *start == 1.0 && *end == 1.0
-                        )
-            ),
-            "expected NotFoundAt naming frame b with the covered range, got {result:?}"
+        registry.remap_frame("base_link", "base");
+
+        let snapshot = registry.freeze_at(query);
+        assert_eq!(snapshot.timestamp(), query);
+        // Legacy names resolve like on the live registry.
+        assert_eq!(
+            snapshot.get_transform("map", "base_link").unwrap(),
+            registry.get_transform("map", "base_link", query).unwrap()
+        );
+        assert_eq!(snapshot.len(), 2);
+        assert!(snapshot.edge("object").is_none());
+        assert_eq!(
+            snapshot.edge("base").unwrap().translation,
+            Vector3::new(2.0, 0.0, 0.0)
         );
+
+        // Later changes to the registry do not leak into the snapshot.
+        registry.delete_transforms_before(t2);
+        let result = snapshot.get_transform("map", "lidar").unwrap();
+        assert_eq!(result.translation, Vector3::new(2.5, 0.0, 0.0));
+        assert_eq!(result.timestamp, query);
+        assert!(registry.get_transform("map", "lidar", query).is_err());
+
+        // A left-out edge is missing from the snapshot's tree, while the
+        // registry reports the missing sample.
+        assert!(matches!(
+            registry.get_transform("map", "object", query),
+            Err(TransformError::NotFoundAt { .. })
+        ));
+        assert!(matches!(
+            snapshot.get_transform("map", "object"),
+            Err(TransformError::UnknownFrame(frame)) if frame == "object"
+        ));
     }
 
     #[test]
-    fn get_transform_mid_chain_gap_reports_gap_frame() {
-        // Tree: r -> a -> b -> c. The a -> b hop is only known at t1; the
-        // others are known at t1 and t3. A query at t2 hits a timestamp gap
-        // in the MIDDLE of the chain, so both partial walks stop in
-        // different subtrees. That is a transient data gap and must be
-        // reported as NotFoundAt naming the gap frame — not
-        // IncompatibleFrames, whose "frames do not have a parent-child
-        // relationship" message is false here.
-        let mut registry = Registry::new();
-        let t1 = Timestamp::from_nanos(1_000_000_000);
-        let t2 = Timestamp::from_nanos(2_000_000_000);
-        let t3 = Timestamp::from_nanos(3_000_000_000);
+    fn output_does_not_depend_on_insert_order() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let edges = ["a", "b", "c", "d", "e", "f", "g", "h"].map(|child| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: t,
+            parent: "root".into(),
+            child: child.into(),
+        });
 
-        for &t in &[t1, t3] {
+        let mut forward = Registry::new();
+        let mut backward = Registry::new();
+        for tf in &edges {
+            forward.add_transform(tf.clone()).unwrap();
+            forward.remap_frame(&tf.child.to_uppercase(), &tf.child);
+        }
+        for tf in edges.iter().rev() {
+            backward.add_transform(tf.clone()).unwrap();
+            backward.remap_frame(&tf.child.to_uppercase(), &tf.child);
+        }
+
+        assert_eq!(format!("{forward:?}"), format!("{backward:?}"));
+        let forward_snapshot = forward.freeze_at(t);
+        let backward_snapshot = backward.freeze_at(t);
+        assert_eq!(
+            format!("{forward_snapshot:?}"),
+            format!("{backward_snapshot:?}")
+        );
+        let children: Vec<&str> = forward_snapshot
+            .edges()
+            .map(|tf| tf.child.as_str())
+            .collect();
+        assert_eq!(children, ["a", "b", "c", "d", "e", "f", "g", "h"]);
+    }
+
+    #[test]
+    fn diff_reports_missing_and_diverging_edges_sorted_by_child() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let edge = |parent: &str, child: &str, x: f64| Transform {
+            translation: Vector3::new(x, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: t,
+            parent: parent.into(),
+            child: child.into(),
+        };
+
+        let mut live = Registry::new();
+        let mut replay = Registry::new();
+        for registry in [&mut live, &mut replay] {
+            registry.add_transform(edge("map", "base", 1.0)).unwrap();
+        }
+        live.add_transform(edge("base", "lidar", 0.5)).unwrap();
+        replay
+            .add_transform(edge("base", "lidar", 0.5 + 1e-12))
+            .unwrap();
+        live.add_transform(edge("base", "imu", 0.1)).unwrap();
+        replay.add_transform(edge("base", "camera", 0.2)).unwrap();
+        live.add_transform(edge("map", "target", 2.0)).unwrap();
+        replay.add_transform(edge("map", "target", 2.5)).unwrap();
+
+        let diff = live.diff(&replay, 1e-9);
+
+        assert_eq!(diff.len(), 3);
+        assert_eq!(diff[0], EdgeDiff::OnlyInOther("camera".into()));
+        assert_eq!(diff[1], EdgeDiff::OnlyInSelf("imu".into()));
+        assert_eq!(
+            diff[2],
+            EdgeDiff::LatestDiverges {
+                this: edge("map", "target", 2.0),
+                other: edge("map", "target", 2.5),
+            }
+        );
+        assert_eq!(diff[2].child(), "target");
+        assert!(live.diff(&live, 0.0).is_empty());
+    }
+
+    #[test]
+    fn lookup_cache_answers_repeated_lookups() {
+        let mut registry = Registry::new();
+        for nanos in [1_000_000_000, 2_000_000_000] {
             registry
                 .add_transform(Transform {
                     translation: Vector3::new(1.0, 0.0, 0.0),
                     rotation: Quaternion::identity(),
-                    timestamp: t,
-                    parent: "r".into(),
-                    child: "a".into(),
+                    timestamp: Timestamp::from_nanos(nanos),
+                    parent: "map".into(),
+                    child: "base".into(),
                 })
                 .unwrap();
+        }
+
+        let t1 = Timestamp::from_nanos(1_500_000_000);
+        let t2 = Timestamp::from_nanos(1_750_000_000);
+        let mut cache = LookupCache::new(&registry);
+        assert!(cache.is_empty());
+
+        let first = cache.get_transform("map", "base", t1).unwrap();
+        assert_eq!(first, registry.get_transform("map", "base", t1).unwrap());
+        assert_eq!(cache.get_transform("map", "base", t1).unwrap(), first);
+        assert_eq!(cache.len(), 1);
+
+        // Other times and directions are separate entries.
+        cache.get_transform("map", "base", t2).unwrap();
+        cache.get_transform("base", "map", t1).unwrap();
+        assert_eq!(cache.len(), 3);
+
+        // Failures are not cached.
+        assert!(cache.get_transform("map", "ghost", t1).is_err());
+        assert_eq!(cache.len(), 3);
+        assert!(cache.registry().is_connected("map", "base"));
+    }
+
+    #[test]
+    fn with_capacity_presizes_the_frame_map() {
+        let mut registry = Registry::with_capacity(200);
+        let capacity = registry.data.capacity();
+        assert!(capacity >= 200);
+
+        for i in 0..200 {
             registry
                 .add_transform(Transform {
-                    translation: Vector3::new(0.0, 0.0, 1.0),
+                    translation: Vector3::new(1.0, 0.0, 0.0),
                     rotation: Quaternion::identity(),
-                    timestamp: t,
-                    parent: "b".into(),
-                    child: "c".into(),
+                    timestamp: Timestamp::zero(),
+                    parent: "map".into(),
+                    child: i.to_string(),
                 })
                 .unwrap();
         }
-        registry
-            .add_transform(Transform {
-                translation: Vector3::new(0.0, 1.0, 0.0),
-                rotation: Quaternion::identity(),
-                timestamp: t1,
-                parent: "a".into(),
-                child: "b".into(),
-            })
-            .unwrap();
+        assert_eq!(registry.data.capacity(), capacity);
+        assert!(registry.buffer("0").unwrap().config().max_age().is_none());
+    }
 
-        // With all hops resolvable (t1) the chain works: the topology is
-        // intact and only the data gap at t2 must trip the lookup.
-        let result = registry.get_transform("a", "c", t1);
-        assert!(
-            result.is_ok(),
-            "expected chain at t1 to resolve: {result:?}"
-        );
+    #[test]
+    fn iter_transforms_yields_every_stored_transform_in_order() {
+        let mut registry = Registry::new();
+        assert_eq!(registry.iter_transforms().count(), 0);
 
-        let result = registry.get_transform("a", "c", t2);
-        assert!(
-            matches!(&result, Err(TransformError::NotFoundAt { frame, .. }) if frame == "b"),
-            "expected NotFoundAt naming the gap frame b, got {result:?}"
+        let transform = |parent: &str, child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        for tf in [
+            transform("odom", "base", 2_000_000_000),
+            transform("base", "lidar", 0),
+            transform("odom", "base", 1_000_000_000),
+            transform("map", "odom", 1_500_000_000),
+        ] {
+            registry.add_transform(tf).unwrap();
+        }
+
+        let stored: Vec<(&str, &str, u128)> = registry
+            .iter_transforms()
+            .map(|(parent, child, tf)| (parent, child, tf.timestamp.as_nanos()))
+            .collect();
+        assert_eq!(
+            stored,
+            [
+                ("odom", "base", 1_000_000_000),
+                ("odom", "base", 2_000_000_000),
+                ("base", "lidar", 0),
+                ("map", "odom", 1_500_000_000),
+            ]
         );
     }
 
     #[test]
-    fn get_transform_disconnected_trees_returns_disconnected() {
-        // Two disjoint trees: r1 -> a and r2 -> b. There is no path between
-        // "a" and "b", which must be reported as Disconnected — not as a
-        // failed composition of the two unrelated root transforms, and not
-        // as a data gap: both frames exist and both walks complete cleanly,
-        // so the disconnection is a statement about the current topology.
+    fn stats_reports_per_frame_sizes_and_time_ranges() {
         let mut registry = Registry::new();
-        let t = Timestamp::from_nanos(1_000_000_000);
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
 
         registry
             .add_transform(Transform {
-                translation: Vector3::new(1.0, 0.0, 0.0),
-                rotation: Quaternion::identity(),
-                timestamp: t,
-                parent: "r1".into(),
-                child: "a".into(),
-            })
-            .unwrap();
-        registry
-            .add_transform(Transform {
-                translation: Vector3::new(0.0, 1.0, 0.0),
+                translation: Vector3::new(0.5, 0.0, 0.0),
                 rotation: Quaternion::identity(),
-                timestamp: t,
-                parent: "r2".into(),
-                child: "b".into(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        for &t in &[t1, t2] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "map".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
+
+        let stats = registry.stats();
+
+        assert_eq!(stats.total_transforms, 3);
+        assert_eq!(stats.frames.len(), 2);
+        let base = &stats.frames[0];
+        assert_eq!(base.child, "base");
+        assert_eq!(base.parent, "map");
+        assert!(!base.is_static);
+        assert_eq!(base.len, 2);
+        assert_eq!(base.oldest, Some(t1));
+        assert_eq!(base.newest, Some(t2));
+        let lidar = &stats.frames[1];
+        assert!(lidar.is_static);
+        assert_eq!(lidar.oldest, Some(Timestamp::zero()));
+        assert!(base.estimated_bytes > lidar.estimated_bytes);
+        assert_eq!(
+            stats.estimated_bytes,
+            base.estimated_bytes + lidar.estimated_bytes
+        );
+        assert_eq!(Registry::<Timestamp>::new().stats().total_transforms, 0);
+    }
+
+    #[test]
+    fn transform_chain_lists_the_edges_of_a_lookup() {
+        let mut registry = Registry::new();
+        let transform = |parent: &str, child: &str, x, nanos| Transform {
+            translation: Vector3::new(x, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        registry
+            .add_transform(transform("map", "odom", 1.0, 1_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("map", "odom", 3.0, 2_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("odom", "base", 0.5, 0))
+            .unwrap();
+        registry
+            .add_transform(transform("map", "dock", 4.0, 0))
+            .unwrap();
+        registry
+            .add_transform(transform("world", "island", 1.0, 0))
+            .unwrap();
+
+        let t = Timestamp::from_nanos(1_500_000_000);
+        let chain = registry.transform_chain("base", "dock", t).unwrap();
+        let edges: Vec<(&str, &str)> = chain
+            .iter()
+            .map(|tf| (tf.parent.as_str(), tf.child.as_str()))
+            .collect();
+        assert_eq!(edges, [("odom", "base"), ("map", "odom"), ("map", "dock")]);
+        assert_eq!(chain[1].translation, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq!(chain[1].timestamp, t);
+
+        // Downward chains run from the ancestor to the descendant.
+        let chain = registry.transform_chain("map", "base", t).unwrap();
+        assert_eq!(chain[0].child, "odom");
+        assert_eq!(chain[1].child, "base");
+        assert!(
+            registry
+                .transform_chain("base", "base", t)
+                .unwrap()
+                .is_empty()
+        );
+
+        assert!(matches!(
+            registry.transform_chain("base", "ghost", t),
+            Err(TransformError::UnknownFrame(frame)) if frame == "ghost"
+        ));
+        assert!(matches!(
+            registry.transform_chain("base", "island", t),
+            Err(TransformError::Disconnected(..))
+        ));
+        assert!(matches!(
+            registry.transform_chain("base", "dock", Timestamp::from_nanos(3_000_000_000)),
+            Err(TransformError::NotFoundAt { frame, .. }) if frame == "odom"
+        ));
+    }
+
+    #[test]
+    fn path_between_lists_the_frames_of_a_route() {
+        let mut registry = Registry::new();
+        for (parent, child) in [
+            ("map", "odom"),
+            ("odom", "base"),
+            ("base", "camera"),
+            ("map", "dock"),
+            ("world", "island"),
+        ] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::from_nanos(1_000_000_000),
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+
+        assert_eq!(
+            registry.path_between("camera", "dock").unwrap(),
+            ["camera", "base", "odom", "map", "dock"]
+        );
+        assert_eq!(
+            registry.path_between("map", "camera").unwrap(),
+            ["map", "odom", "base", "camera"]
+        );
+        assert_eq!(
+            registry.path_between("base", "odom").unwrap(),
+            ["base", "odom"]
+        );
+        assert_eq!(registry.path_between("base", "base").unwrap(), ["base"]);
+
+        // Remapped names resolve to their current frames.
+        registry.remap_frame("camera_link", "camera");
+        assert_eq!(
+            registry.path_between("camera_link", "odom").unwrap(),
+            ["camera", "base", "odom"]
+        );
+
+        assert!(matches!(
+            registry.path_between("ghost", "map"),
+            Err(TransformError::UnknownFrame(frame)) if frame == "ghost"
+        ));
+        assert!(matches!(
+            registry.path_between("camera", "island"),
+            Err(TransformError::Disconnected(..))
+        ));
+    }
+
+    #[test]
+    fn is_connected_ignores_buffered_times() {
+        let mut registry = Registry::new();
+        let transform = |parent: &str, child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        registry
+            .add_transform(transform("map", "odom", 1_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("odom", "base", 5_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("world", "island", 0))
+            .unwrap();
+
+        // No time serves both edges, yet the frames are connected.
+        let t = Timestamp::from_nanos(1_000_000_000);
+        assert!(registry.get_transform("map", "base", t).is_err());
+        assert!(registry.is_connected("map", "base"));
+        assert!(registry.is_connected("base", "map"));
+        assert!(registry.is_connected("base", "base"));
+
+        assert!(!registry.is_connected("base", "island"));
+        assert!(!registry.is_connected("base", "ghost"));
+    }
+
+    #[test]
+    fn roots_lists_each_tree_of_a_forest() {
+        let mut registry = Registry::new();
+        assert!(registry.roots().is_empty());
+
+        for (parent, child) in [
+            ("map", "odom"),
+            ("odom", "base"),
+            ("map", "dock"),
+            ("world", "island"),
+        ] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::zero(),
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+        assert_eq!(registry.roots(), ["map", "world"]);
+
+        // Joining the trees leaves a single root.
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "world".into(),
+                child: "map".into(),
+            })
+            .unwrap();
+        assert_eq!(registry.roots(), ["world"]);
+    }
+
+    #[test]
+    fn validate_reports_forests_and_dangling_remaps() {
+        let mut registry = Registry::new();
+        assert!(registry.validate().is_empty());
+
+        for (parent, child) in [("map", "odom"), ("odom", "base"), ("world", "island")] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::zero(),
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+        registry.remap_frame("base_link", "base");
+        registry.remap_frame("laser", "lidar");
+        registry.remap_frame("cam", "camera");
+
+        assert_eq!(
+            registry.validate(),
+            [
+                TreeIssue::Forest(vec!["map".into(), "world".into()]),
+                TreeIssue::DanglingRemap {
+                    from: "cam".into(),
+                    to: "camera".into(),
+                },
+                TreeIssue::DanglingRemap {
+                    from: "laser".into(),
+                    to: "lidar".into(),
+                },
+            ]
+        );
+
+        registry.remove_frame("island");
+        registry.remove_remap("cam");
+        registry.remove_remap("laser");
+        assert!(registry.validate().is_empty());
+    }
+
+    #[test]
+    fn ancestors_and_descendants_walk_the_tree() {
+        let mut registry = Registry::new();
+        for (parent, child) in [
+            ("map", "odom"),
+            ("odom", "base"),
+            ("base", "lidar"),
+            ("base", "camera"),
+            ("camera", "optical"),
+            ("map", "dock"),
+        ] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::from_nanos(1_000_000_000),
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+
+        assert_eq!(
+            registry.ancestors("optical"),
+            ["camera", "base", "odom", "map"]
+        );
+        assert!(registry.ancestors("map").is_empty());
+        assert!(registry.ancestors("ghost").is_empty());
+
+        assert_eq!(
+            registry.descendants("map"),
+            ["dock", "odom", "base", "camera", "lidar", "optical"]
+        );
+        assert_eq!(registry.descendants("base"), ["camera", "lidar", "optical"]);
+        assert!(registry.descendants("lidar").is_empty());
+        assert!(registry.descendants("ghost").is_empty());
+    }
+
+    #[test]
+    fn stale_frames_reports_silent_dynamic_edges() {
+        let mut registry = Registry::new();
+        let transform = |parent: &str, child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        registry
+            .add_transform(transform("base", "lidar", 0))
+            .unwrap();
+        registry
+            .add_transform(transform("odom", "base", 9_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("base", "camera", 7_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("map", "odom", 3_000_000_000))
+            .unwrap();
+
+        let now = Timestamp::from_nanos(10_000_000_000);
+        let stale = registry.stale_frames(now, Duration::from_secs(2));
+        let children: Vec<&str> = stale.iter().map(|frame| frame.child.as_str()).collect();
+        assert_eq!(children, ["camera", "odom"]);
+        assert_eq!(stale[0].parent, "base");
+        assert_eq!(stale[0].newest, Some(Timestamp::from_nanos(7_000_000_000)));
+
+        // Exactly `max_silence` is not stale yet, nor is a stamp after `now`.
+        assert!(
+            registry
+                .stale_frames(Timestamp::from_nanos(5_000_000_000), Duration::from_secs(2))
+                .is_empty()
+        );
+        assert!(
+            registry
+                .stale_frames(Timestamp::zero(), Duration::ZERO)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn diagnostics_reports_every_edge() {
+        let mut registry = Registry::new();
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        for nanos in [1_000_000_000, 1_500_000_000] {
+            registry
+                .add_transform_with_authority(
+                    Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: Timestamp::from_nanos(nanos),
+                        parent: "odom".into(),
+                        child: "base".into(),
+                    },
+                    "wheels",
+                )
+                .unwrap();
+        }
+
+        let now = Timestamp::from_nanos(2_000_000_000);
+        let report = registry.diagnostics(now);
+        assert_eq!(report.now, now);
+        assert_eq!(report.edges.len(), 2);
+        let base = &report.edges[0];
+        assert_eq!(base.child, "base");
+        assert_eq!(base.delay, Some(Duration::from_millis(500)));
+        assert_eq!(base.span, Some(Duration::from_millis(500)));
+        assert_eq!(base.authority.as_deref(), Some("wheels"));
+        let lidar = &report.edges[1];
+        assert!(lidar.is_static);
+        assert_eq!(lidar.delay, None);
+        assert_eq!(lidar.rate, None);
+        assert_eq!(
+            report.to_string(),
+            "odom -> base: 2.000 Hz, delay 0.500 s, span 0.500 s, authority wheels\n\
+             base -> lidar: static, authority unknown\n"
+        );
+
+        // An edge stamped after `now` has no delay.
+        let early = registry.diagnostics(Timestamp::from_nanos(1_000_000_000));
+        assert_eq!(early.edges[0].delay, None);
+        assert!(early.edges[0].to_string().contains("delay unknown"));
+    }
+
+    #[test]
+    fn authority_tracks_the_latest_writer_of_each_frame() {
+        let mut registry = Registry::new();
+        let transform = |nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: "odom".into(),
+            child: "base".into(),
+        };
+
+        registry
+            .add_transform_with_authority(transform(1_000_000_000), "wheels")
+            .unwrap();
+        registry
+            .add_transform_with_authority(transform(2_000_000_000), "visual")
+            .unwrap();
+        assert_eq!(registry.buffer("base").unwrap().authority(), Some("visual"));
+        assert_eq!(
+            registry.stats().frames[0].authority.as_deref(),
+            Some("visual")
+        );
+
+        // A rejected write does not claim the frame.
+        let mut rejected = transform(3_000_000_000);
+        rejected.parent = "map".into();
+        assert!(
+            registry
+                .add_transform_with_authority(rejected, "imposter")
+                .is_err()
+        );
+        assert_eq!(registry.buffer("base").unwrap().authority(), Some("visual"));
+
+        // The authority follows renames and is cleared by anonymous writes.
+        registry.rename_frame("base", "base_link").unwrap();
+        assert_eq!(
+            registry.buffer("base_link").unwrap().authority(),
+            Some("visual")
+        );
+        let mut anonymous = transform(3_000_000_000);
+        anonymous.child = "base_link".into();
+        registry.add_transform(anonymous).unwrap();
+        assert_eq!(registry.buffer("base_link").unwrap().authority(), None);
+    }
+
+    #[test]
+    fn frame_metadata_follows_renames_and_removals() {
+        let mut registry = Registry::new();
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar_tmp".into(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            registry.set_frame_metadata("lidar_tmp", "serial", "A1"),
+            None
+        );
+        assert_eq!(
+            registry.set_frame_metadata("lidar_tmp", "serial", "B2"),
+            Some("A1".into())
+        );
+        registry.set_frame_metadata("lidar_tmp", "model", "LX");
+        // Frames may be annotated before their first transform arrives.
+        registry.set_frame_metadata("camera", "model", "CX");
+
+        registry.rename_frame("lidar_tmp", "lidar").unwrap();
+        assert!(registry.frame_metadata("lidar_tmp").is_none());
+        let metadata = registry.frame_metadata("lidar").unwrap();
+        let entries: Vec<(&str, &str)> = metadata
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        assert_eq!(entries, [("model", "LX"), ("serial", "B2")]);
+
+        assert_eq!(
+            registry.remove_frame_metadata("camera", "model").as_deref(),
+            Some("CX")
+        );
+        assert!(registry.frame_metadata("camera").is_none());
+
+        registry.remove_frame("lidar");
+        assert!(registry.frame_metadata("lidar").is_none());
+    }
+
+    #[test]
+    fn remapped_frames_are_stored_and_looked_up_by_their_current_names() {
+        let mut registry = Registry::builder()
+            .remap_frame("base_footprint", "base")
+            .build();
+        registry.remap_frame("laser", "lidar");
+        for (parent, child, x) in [
+            ("map", "base_footprint", 1.0),
+            ("base_footprint", "laser", 0.5),
+        ] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(x, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::zero(),
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+
+        assert!(registry.buffer("base_footprint").is_none());
+        assert_eq!(registry.buffer("lidar").unwrap().parent(), Some("base"));
+        for (from, to) in [("map", "lidar"), ("map", "laser")] {
+            let result = registry.get_transform(from, to, Timestamp::zero()).unwrap();
+            assert_eq!(result.translation, Vector3::new(1.5, 0.0, 0.0));
+            assert_eq!(result.child, "lidar");
+        }
+        assert!(registry.is_static("base_footprint", "laser").unwrap());
+
+        assert_eq!(registry.remove_remap("laser").as_deref(), Some("lidar"));
+        assert!(matches!(
+            registry.get_transform("map", "laser", Timestamp::zero()),
+            Err(TransformError::UnknownFrame(frame)) if frame == "laser"
+        ));
+    }
+
+    #[test]
+    fn remap_table_stays_single_step() {
+        let mut registry = Registry::<Timestamp>::new();
+
+        // Chains collapse onto the current name.
+        registry.remap_frame("a", "b");
+        registry.remap_frame("b", "c");
+        assert_eq!(registry.resolve_frame("a"), "c");
+        assert_eq!(registry.resolve_frame("b"), "c");
+        assert_eq!(registry.resolve_frame("c"), "c");
+        registry.remap_frame("x", "a");
+        assert_eq!(registry.resolve_frame("x"), "c");
+
+        // Remapping back onto a legacy name replaces the reverse remap.
+        registry.remap_frame("c", "a");
+        assert_eq!(registry.resolve_frame("a"), "a");
+        assert_eq!(registry.resolve_frame("b"), "a");
+        assert_eq!(registry.resolve_frame("c"), "a");
+        assert_eq!(registry.resolve_frame("x"), "a");
+
+        // A self-remap removes the remap.
+        registry.remap_frame("b", "b");
+        assert_eq!(registry.resolve_frame("b"), "b");
+    }
+
+    #[test]
+    fn add_static_transform_ignores_the_timestamp() {
+        let mut registry = Registry::new();
+        registry
+            .add_static_transform(Transform {
+                translation: Vector3::new(0.0, 0.0, 0.3),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::from_nanos(5_000_000_000),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+
+        let buffer = registry.buffer("lidar").unwrap();
+        assert!(buffer.is_static());
+        assert_eq!(buffer.newest(), Some(Timestamp::zero()));
+        // Valid for all time, not just at the given stamp.
+        let requested = Timestamp::from_nanos(1_000_000_000);
+        let result = registry.get_transform("base", "lidar", requested).unwrap();
+        assert_eq!(result.timestamp, requested);
+
+        // The frame is static now; dynamic samples still conflict.
+        let result = registry.add_transform(Transform {
+            translation: Vector3::new(0.0, 0.0, 0.3),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(5_000_000_000),
+            parent: "base".into(),
+            child: "lidar".into(),
+        });
+        assert!(matches!(result, Err(BufferError::StaticDynamicConflict)));
+    }
+
+    #[test]
+    fn is_static_reports_whether_the_whole_path_is_static() {
+        let mut registry = Registry::new();
+        let transform = |parent: &str, child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        registry
+            .add_transform(transform("map", "base", 1_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("base", "lidar", 0))
+            .unwrap();
+        registry
+            .add_transform(transform("base", "camera", 0))
+            .unwrap();
+        registry
+            .add_transform(transform("world", "dock", 0))
+            .unwrap();
+
+        assert!(registry.is_static("lidar", "camera").unwrap());
+        assert!(registry.is_static("base", "lidar").unwrap());
+        assert!(registry.is_static("map", "map").unwrap());
+        assert!(!registry.is_static("map", "lidar").unwrap());
+        assert!(!registry.is_static("camera", "map").unwrap());
+        assert!(matches!(
+            registry.is_static("map", "dock"),
+            Err(TransformError::Disconnected(..))
+        ));
+        assert!(matches!(
+            registry.is_static("map", "bsae"),
+            Err(TransformError::UnknownFrame(frame)) if frame == "bsae"
+        ));
+    }
+
+    #[test]
+    fn latest_transform_uses_latest_common_time_of_the_path() {
+        let mut registry = Registry::new();
+        let edges = [
+            ("map", "odom", [1, 4]),
+            ("odom", "base", [1, 3]),
+            ("map", "target", [2, 5]),
+        ];
+        for (parent, child, secs) in edges {
+            for s in secs {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: Timestamp::from_nanos(s * 1_000_000_000),
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+
+        // The static lidar mount does not limit the time; "base" does.
+        let result = registry.latest_transform("map", "lidar").unwrap();
+        assert_eq!(result.timestamp, Timestamp::from_nanos(3_000_000_000));
+        assert_eq!(result.translation, Vector3::new(2.5, 0.0, 0.0));
+
+        // Across the tree, the edge with the oldest newest sample wins; the
+        // shared "map" root contributes no edge of its own.
+        let result = registry.latest_transform("target", "base").unwrap();
+        assert_eq!(result.timestamp, Timestamp::from_nanos(3_000_000_000));
+
+        // A purely static path resolves at the static timestamp.
+        let result = registry.latest_transform("base", "lidar").unwrap();
+        assert_eq!(result.timestamp, Timestamp::zero());
+
+        assert!(matches!(
+            registry.latest_transform("map", "missing"),
+            Err(TransformError::UnknownFrame(frame)) if frame == "missing"
+        ));
+    }
+
+    #[test]
+    fn get_transform_exact_requires_samples_on_every_dynamic_edge() {
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+        let t3 = Timestamp::from_nanos(3_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        for (child, parent, stamps) in [("base", "map", [t1, t3]), ("map", "world", [t1, t2])] {
+            for t in stamps {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: t,
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+
+        let result = registry.get_transform_exact("world", "lidar", t1).unwrap();
+        assert_eq!(result.translation, Vector3::new(2.5, 0.0, 0.0));
+        assert_eq!(result.timestamp, t1);
+
+        // Interpolation would serve t2 on "base"; an exact lookup must not.
+        assert!(registry.get_transform("world", "lidar", t2).is_ok());
+        let err = registry
+            .get_transform_exact("world", "lidar", t2)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::NotFoundAt { frame, source, .. }
+                if frame == "base" && matches!(*source, BufferError::NoExactMatch(_))
+        ));
+    }
+
+    #[test]
+    fn lookup_error_messages_tell_range_gaps_from_topology_and_typos() {
+        let mut registry = Registry::new();
+        for (parent, child, nanos) in [
+            ("map", "base", 1_000_000_000),
+            ("map", "base", 2_000_000_000),
+            ("world", "dock", 0),
+        ] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::from_nanos(nanos),
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+        let message = |from, to| {
+            registry
+                .get_transform(from, to, Timestamp::from_nanos(3_000_000_000))
+                .unwrap_err()
+                .to_string()
+        };
+
+        // Outside the covered range: frames, requested time and range.
+        assert_eq!(
+            message("map", "base"),
+            "transform not found from map to base (frame base: transform error: \
+             requested timestamp 3 is outside the covered range [1, 2])"
+        );
+        // Different trees, both walks clean.
+        assert_eq!(
+            message("map", "dock"),
+            "no transform chain connects map and dock"
+        );
+        // A misspelled frame.
+        assert_eq!(
+            message("map", "bsae"),
+            "frame bsae does not exist in the transform tree"
+        );
+    }
+
+    #[test]
+    fn get_transform_nearest_composes_closest_samples_at_the_requested_time() {
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(2_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        // The two dynamic edges are sampled at different times; neither
+        // sample lies exactly at the query.
+        for (parent, child, samples) in [
+            ("map", "base", [(1_900_000_000, 1.0), (3_000_000_000, 3.0)]),
+            (
+                "world",
+                "map",
+                [(1_000_000_000, 10.0), (2_050_000_000, 20.0)],
+            ),
+        ] {
+            for (nanos, x) in samples {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(x, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: Timestamp::from_nanos(nanos),
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+
+        let result = registry
+            .get_transform_nearest("world", "lidar", t, Some(Duration::from_millis(100)))
+            .unwrap();
+        assert_eq!(result.translation, Vector3::new(21.5, 0.0, 0.0));
+        assert_eq!(result.timestamp, t);
+
+        let err = registry
+            .get_transform_nearest("world", "lidar", t, Some(Duration::from_millis(60)))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::NotFoundAt { frame, source, .. }
+                if frame == "base" && matches!(*source, BufferError::NoSampleWithinTolerance(_))
+        ));
+    }
+
+    #[test]
+    fn get_transform_nearest_does_not_extrapolate_within_the_tolerance() {
+        let mut registry = Registry::new();
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::from_nanos(1_000_000_000),
+                parent: "map".into(),
+                child: "base".into(),
+            })
+            .unwrap();
+
+        // 400 ms past the only sample: within the tolerance, but outside the
+        // covered range, so the sample is not relabelled as the future time.
+        let err = registry
+            .get_transform_nearest(
+                "map",
+                "base",
+                Timestamp::from_nanos(1_400_000_000),
+                Some(Duration::from_millis(500)),
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::NotFoundAt { frame, source, .. }
+                if frame == "base" && matches!(
+                    *source,
+                    BufferError::TransformError(TransformError::TimestampOutOfRange(..))
+                )
+        ));
+    }
+
+    #[test]
+    fn lookup_velocity_returns_the_body_twist_of_the_child() {
+        let mut registry = Registry::new();
+
+        // Over two seconds the base turns left by 90 degrees while moving
+        // two units along the odom y-axis.
+        for (nanos, translation, rotation) in [
+            (1_000_000_000, Vector3::zero(), Quaternion::identity()),
+            (
+                3_000_000_000,
+                Vector3::new(0.0, 2.0, 0.0),
+                Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2),
+            ),
+        ] {
+            registry
+                .add_transform(Transform {
+                    translation,
+                    rotation,
+                    timestamp: Timestamp::from_nanos(nanos),
+                    parent: "odom".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
+
+        let t = Timestamp::from_nanos(3_000_000_000);
+        let twist = registry
+            .lookup_velocity("odom", "base", t, Duration::from_secs(2))
+            .unwrap();
+
+        // Expressed in the final base frame, the motion was straight ahead.
+        assert_abs_diff_eq!(twist.linear, Vector3::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            twist.angular,
+            Vector3::new(0.0, 0.0, core::f64::consts::FRAC_PI_4),
+            epsilon = 1e-12
+        );
+        assert_eq!(twist.timestamp, t);
+        assert_eq!(twist.frame, "base");
+    }
+
+    #[test]
+    fn lookup_velocity_reports_unusable_windows() {
+        let mut registry = Registry::new();
+        for nanos in [1_000_000_000, 2_000_000_000] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::zero(),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::from_nanos(nanos),
+                    parent: "odom".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
+        let t = Timestamp::from_nanos(2_000_000_000);
+
+        assert!(matches!(
+            registry.lookup_velocity("odom", "base", t, Duration::ZERO),
+            Err(TransformError::ZeroVelocityWindow)
+        ));
+        assert!(matches!(
+            registry.lookup_velocity("odom", "base", t, Duration::from_secs(3)),
+            Err(TransformError::TimestampError(_))
+        ));
+        assert!(matches!(
+            registry.lookup_velocity("odom", "base", t, Duration::from_millis(1_500)),
+            Err(TransformError::NotFoundAt { .. })
+        ));
+    }
+
+    #[test]
+    fn get_transform_with_tolerance_moves_into_the_common_coverage() {
+        let mut registry = Registry::new();
+        let ms = |ms: u128| Timestamp::from_nanos(ms * 1_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "camera".into(),
+            })
+            .unwrap();
+        for (parent, child, range) in [
+            ("map", "base", (1000, 2000)),
+            ("world", "map", (1500, 3000)),
+        ] {
+            for s in [range.0, range.1] {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: ms(s),
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+        let tolerance = Duration::from_millis(100);
+
+        // Inside the common coverage [1.5, 2.0]: a plain lookup.
+        let result = registry
+            .get_transform_with_tolerance("world", "camera", ms(1750), tolerance)
+            .unwrap();
+        assert_eq!(result.timestamp, ms(1750));
+
+        // Just past "base"'s newest sample, and just before "map"'s oldest:
+        // the result is stamped with the time it was resolved at.
+        let result = registry
+            .get_transform_with_tolerance("world", "camera", ms(2050), tolerance)
+            .unwrap();
+        assert_eq!(result.timestamp, ms(2000));
+        assert_eq!(result.translation, Vector3::new(2.5, 0.0, 0.0));
+        let result = registry
+            .get_transform_with_tolerance("world", "camera", ms(1450), tolerance)
+            .unwrap();
+        assert_eq!(result.timestamp, ms(1500));
+
+        // Too far away: the error of the plain lookup.
+        let err = registry
+            .get_transform_with_tolerance("world", "camera", ms(2500), tolerance)
+            .unwrap_err();
+        assert!(matches!(err, TransformError::NotFoundAt { frame, .. } if frame == "base"));
+    }
+
+    #[test]
+    fn get_transform_with_tolerance_snaps_to_a_servable_sample() {
+        let ms = |ms: u128| Timestamp::from_nanos(ms * 1_000_000);
+        let transform = |parent: &str, child: &str, x, t| Transform {
+            translation: Vector3::new(x, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: t,
+            parent: parent.into(),
+            child: child.into(),
+        };
+        let tolerance = Duration::from_millis(100);
+
+        // Without interpolation, only the stored sample inside the window
+        // can serve the lookup.
+        let mut registry = Registry::with_config(
+            BufferConfig::new().with_interpolation(InterpolationPolicy::None),
+        );
+        for (x, t) in [(1.0, ms(1000)), (2.0, ms(2000))] {
+            registry
+                .add_transform(transform("map", "base", x, t))
+                .unwrap();
+        }
+        let result = registry
+            .get_transform_with_tolerance("map", "base", ms(1030), tolerance)
+            .unwrap();
+        assert_eq!(result.timestamp, ms(1000));
+        assert_eq!(result.translation, Vector3::new(1.0, 0.0, 0.0));
+
+        // "base" would be served at 2.0 s, but "map" only holds samples at
+        // 1.0 s and 2.1 s: the failing retry is not reported, the original
+        // miss at 2.08 s is.
+        for (x, t) in [(1.0, ms(1000)), (2.0, ms(2100))] {
+            registry
+                .add_transform(transform("odom", "map", x, t))
+                .unwrap();
+        }
+        let err = registry
+            .get_transform_with_tolerance("odom", "base", ms(2080), tolerance)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::NotFoundAt { frame, source, .. }
+                if frame == "base" && matches!(
+                    *source,
+                    BufferError::NoExactMatch(requested) if (requested - 2.08).abs() < 1e-9
+                )
+        ));
+    }
+
+    #[test]
+    fn explain_transform_reports_the_samples_behind_each_edge() {
+        let mut registry = Registry::new();
+        let transform = |parent: &str, child: &str, x, nanos| Transform {
+            translation: Vector3::new(x, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        for (x, nanos) in [(1.0, 1_000_000_000), (3.0, 3_000_000_000)] {
+            registry
+                .add_transform(transform("map", "base", x, nanos))
+                .unwrap();
+        }
+        registry
+            .add_transform(transform("base", "lidar", 0.5, 0))
+            .unwrap();
+
+        let t = Timestamp::from_nanos(2_000_000_000);
+        let explanation = registry.explain_transform("lidar", "map", t).unwrap();
+        assert_eq!(
+            explanation.transform,
+            registry.get_transform("lidar", "map", t).unwrap()
+        );
+        let edges: Vec<(&str, (Timestamp, Timestamp))> = explanation
+            .edges
+            .iter()
+            .map(|edge| (edge.transform.child.as_str(), edge.sources))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ("lidar", (Timestamp::zero(), Timestamp::zero())),
+                (
+                    "base",
+                    (
+                        Timestamp::from_nanos(1_000_000_000),
+                        Timestamp::from_nanos(3_000_000_000)
+                    )
+                ),
+            ]
+        );
+
+        // A held sample is reported once, exact matches likewise.
+        let config = BufferConfig::new().with_interpolation(InterpolationPolicy::Hold);
+        let mut held = Registry::with_config(config);
+        for (x, nanos) in [(1.0, 1_000_000_000), (3.0, 3_000_000_000)] {
+            held.add_transform(transform("map", "base", x, nanos))
+                .unwrap();
+        }
+        let one = Timestamp::from_nanos(1_000_000_000);
+        for query in [t, one] {
+            let explanation = held.explain_transform("map", "base", query).unwrap();
+            assert_eq!(explanation.edges[0].sources, (one, one));
+            assert_eq!(explanation.edges[0].transform.timestamp, query);
+        }
+
+        assert!(matches!(
+            registry.explain_transform("map", "base", Timestamp::from_nanos(4_000_000_000)),
+            Err(TransformError::NotFoundAt { frame, .. }) if frame == "base"
+        ));
+    }
+
+    #[test]
+    fn registry_interpolation_policy_applies_to_every_edge() {
+        let config = BufferConfig::new().with_interpolation(InterpolationPolicy::Hold);
+        let mut registry = Registry::with_config(config);
+
+        for (parent, child, samples) in [
+            ("map", "base", [(1_000_000_000, 1.0), (3_000_000_000, 3.0)]),
+            (
+                "world",
+                "map",
+                [(1_500_000_000, 10.0), (2_500_000_000, 20.0)],
+            ),
+        ] {
+            for (nanos, x) in samples {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(x, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: Timestamp::from_nanos(nanos),
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+
+        // Differently timed samples still compose: both edges hold their
+        // last value and are stamped at the query.
+        let t = Timestamp::from_nanos(2_000_000_000);
+        let result = registry.get_transform("world", "base", t).unwrap();
+        assert_eq!(result.translation, Vector3::new(11.0, 0.0, 0.0));
+        assert_eq!(result.timestamp, t);
+    }
+
+    #[test]
+    fn buffer_exposes_a_child_frames_coverage() {
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+        for &t in &[t1, t2] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "map".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
+
+        let buffer = registry.buffer("base").unwrap();
+        assert_eq!(buffer.parent(), Some("map"));
+        assert_eq!(buffer.time_span().unwrap(), Duration::from_secs(2));
+        // Roots have no buffer of their own.
+        assert!(registry.buffer("map").is_none());
+    }
+
+    #[test]
+    fn rename_frame_updates_key_and_parent_references() {
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+
+        for (parent, child) in [("base", "tmp"), ("tmp", "optical")] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: parent.into(),
+                    child: child.into(),
+                })
+                .unwrap();
+        }
+
+        registry.rename_frame("tmp", "camera").unwrap();
+
+        let base_camera = registry.get_transform("base", "camera", t).unwrap();
+        assert_eq!(base_camera.parent, "base");
+        assert_eq!(base_camera.child, "camera");
+        let camera_optical = registry.get_transform("camera", "optical", t).unwrap();
+        assert_eq!(camera_optical.parent, "camera");
+        assert!(matches!(
+            registry.get_transform("base", "tmp", t),
+            Err(TransformError::UnknownFrame(frame)) if frame == "tmp"
+        ));
+
+        // The renamed frame keeps its pinned parent under the new name.
+        let result = registry.add_transform(Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(2_000_000_000),
+            parent: "map".into(),
+            child: "camera".into(),
+        });
+        assert!(matches!(
+            result,
+            Err(BufferError::ReparentingNotSupported(parent)) if parent == "base"
+        ));
+
+        // Renaming a root, which exists only as a parent, works too.
+        registry.rename_frame("base", "base_link").unwrap();
+        assert!(registry.get_transform("base_link", "optical", t).is_ok());
+    }
+
+    #[test]
+    fn rename_and_remove_frame_keep_remaps_consistent() {
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "base".into(),
+                child: "cam".into(),
+            })
+            .unwrap();
+        registry.remap_frame("legacy", "cam");
+        registry.remap_frame("camera", "elsewhere");
+
+        // The legacy name follows the rename, and the new name is no longer
+        // remapped away.
+        registry.rename_frame("cam", "camera").unwrap();
+        assert_eq!(registry.resolve_frame("legacy"), "camera");
+        assert_eq!(registry.resolve_frame("camera"), "camera");
+        let result = registry.get_transform("base", "legacy", t).unwrap();
+        assert_eq!(result.child, "camera");
+
+        // Removing the frame drops the remaps onto it.
+        registry.remove_frame("camera");
+        assert_eq!(registry.resolve_frame("legacy"), "legacy");
+        assert_eq!(registry.remove_remap("legacy"), None);
+    }
+
+    #[test]
+    fn rename_frame_rejects_unknown_and_existing_names() {
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "a".into(),
+                child: "b".into(),
+            })
+            .unwrap();
+
+        assert!(matches!(
+            registry.rename_frame("missing", "c"),
+            Err(BufferError::UnknownFrame(frame)) if frame == "missing"
+        ));
+        assert!(matches!(
+            registry.rename_frame("b", "a"),
+            Err(BufferError::FrameExists(frame)) if frame == "a"
+        ));
+        registry.rename_frame("b", "b").unwrap();
+        assert!(registry.get_transform("a", "b", t).is_ok());
+    }
+
+    #[test]
+    fn remove_frame_drops_edges_referencing_the_frame() {
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+
+        for (parent, child) in [
+            ("base", "old_sensor"),
+            ("old_sensor", "optical"),
+            ("optical", "pixel"),
+        ] {
+            for &t in &[t1, t2] {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: t,
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+
+        // The sensor's own edge and its child's edge go; the grandchild
+        // edge survives, now detached from "base".
+        assert!(registry.remove_frame("old_sensor"));
+        assert!(registry.buffer("old_sensor").is_none());
+        assert!(registry.buffer("optical").is_none());
+        assert!(registry.get_transform("base", "old_sensor", t1).is_err());
+        assert!(registry.get_transform("old_sensor", "optical", t1).is_err());
+        assert!(registry.get_transform("optical", "pixel", t1).is_ok());
+        assert!(registry.get_transform("base", "pixel", t1).is_err());
+
+        // "optical" is free to attach to a new sensor.
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.0, 1.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "new_sensor".into(),
+                child: "optical".into(),
+            })
+            .unwrap();
+        assert!(!registry.remove_frame("unknown"));
+    }
+
+    #[test]
+    fn remove_frame_resolves_remaps_and_reports_any_removal() {
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        registry.remap_frame("laser", "lidar");
+
+        // The legacy name removes the frame it resolves to.
+        assert!(registry.remove_frame("laser"));
+        assert!(registry.buffer("lidar").is_none());
+        assert_eq!(registry.resolve_frame("laser"), "laser");
+
+        // Metadata alone counts as something to remove.
+        registry.set_frame_metadata("radar", "serial", "R-7");
+        assert!(registry.remove_frame("radar"));
+        assert!(registry.frame_metadata("radar").is_none());
+        assert!(!registry.remove_frame("radar"));
+    }
+
+    #[test]
+    fn delete_transforms_before_prunes_empty_frames() {
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t1,
+                parent: "world".into(),
+                child: "object".into(),
+            })
+            .unwrap();
+
+        // Wiping every transform of a frame releases the frame itself, so
+        // the registry does not accumulate dead frames — and the frame can
+        // come back under a new parent.
+        registry.delete_transforms_before(t2);
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.0, 0.5, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t2,
+                parent: "gripper".into(),
+                child: "object".into(),
+            })
+            .unwrap();
+        assert!(registry.get_transform("gripper", "object", t2).is_ok());
+    }
+
+    #[test]
+    fn get_transform_unknown_frame_returns_not_found() {
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "a".into(),
+                child: "b".into(),
+            })
+            .unwrap();
+
+        // The requested frame does not exist. The walk from "b" still resolves
+        // up to the root "a", but that partial answer must not be returned as
+        // if it were the requested transform; the error names the unknown
+        // frame.
+        let result = registry.get_transform("b", "does_not_exist", t);
+        assert!(
+            matches!(&result, Err(TransformError::UnknownFrame(frame)) if frame == "does_not_exist"),
+            "expected UnknownFrame for unknown target frame, got {result:?}"
+        );
+
+        let result = registry.get_transform("does_not_exist", "b", t);
+        assert!(
+            matches!(&result, Err(TransformError::UnknownFrame(frame)) if frame == "does_not_exist"),
+            "expected UnknownFrame for unknown source frame, got {result:?}"
+        );
+    }
+
+    #[test]
+    // The compared seconds are exactly representable; the assertion is on
+    // the reported values, not on float arithmetic.
+    #[allow(clippy::float_cmp)]
+    fn get_transform_partial_chain_reports_failing_frame() {
+        let mut registry = Registry::new();
+        let t0 = Timestamp::from_nanos(1_000_000_000);
+
+        let t1 = (t0 + Duration::from_secs(1)).unwrap();
+
+        // a -> b is only known at t0; b -> c is known at t0 and t1.
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t0,
+                parent: "a".into(),
+                child: "b".into(),
+            })
+            .unwrap();
+        for &t in &[t0, t1] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(0.0, 1.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "b".into(),
+                    child: "c".into(),
+                })
+                .unwrap();
+        }
+
+        // At t1 only the c -> b hop can be resolved; the chain to "a" is
+        // incomplete and must not be returned as a c -> a transform. The
+        // error pinpoints "b" as the frame that could not serve t1 and
+        // carries the covered range as the cause: b's data ends at t0
+        // (1.0s), one second before the requested t1 (2.0s).
+        let result = registry.get_transform("c", "a", t1);
+        assert!(
+            matches!(
+                &result,
+                Err(TransformError::NotFoundAt { frame, source, .. })
+                    if frame == "b"
+                        && matches!(
+                            source.as_ref(),
+                            BufferError::TransformError(TransformError::TimestampOutOfRange(
+                                requested,
+                                start,
+                                end
+                            )) if *requested == 2.0 && *start == 1.0 && *end == 1.0
+                        )
+            ),
+            "expected NotFoundAt naming frame b with the covered range, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn get_transform_mid_chain_gap_reports_gap_frame() {
+        // Tree: r -> a -> b -> c. The a -> b hop is only known at t1; the
+        // others are known at t1 and t3. A query at t2 hits a timestamp gap
+        // in the MIDDLE of the chain, so both partial walks stop in
+        // different subtrees. That is a transient data gap and must be
+        // reported as NotFoundAt naming the gap frame — not
+        // IncompatibleFrames, whose "frames do not have a parent-child
+        // relationship" message is false here.
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+        let t3 = Timestamp::from_nanos(3_000_000_000);
+
+        for &t in &[t1, t3] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "r".into(),
+                    child: "a".into(),
+                })
+                .unwrap();
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(0.0, 0.0, 1.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "b".into(),
+                    child: "c".into(),
+                })
+                .unwrap();
+        }
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.0, 1.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t1,
+                parent: "a".into(),
+                child: "b".into(),
+            })
+            .unwrap();
+
+        // With all hops resolvable (t1) the chain works: the topology is
+        // intact and only the data gap at t2 must trip the lookup.
+        let result = registry.get_transform("a", "c", t1);
+        assert!(
+            result.is_ok(),
+            "expected chain at t1 to resolve: {result:?}"
+        );
+
+        let result = registry.get_transform("a", "c", t2);
+        assert!(
+            matches!(&result, Err(TransformError::NotFoundAt { frame, .. }) if frame == "b"),
+            "expected NotFoundAt naming the gap frame b, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn get_transform_disconnected_trees_returns_disconnected() {
+        // Two disjoint trees: r1 -> a and r2 -> b. There is no path between
+        // "a" and "b", which must be reported as Disconnected — not as a
+        // failed composition of the two unrelated root transforms, and not
+        // as a data gap: both frames exist and both walks complete cleanly,
+        // so the disconnection is a statement about the current topology.
+        let mut registry = Registry::new();
+        let t = Timestamp::from_nanos(1_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "r1".into(),
+                child: "a".into(),
+            })
+            .unwrap();
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.0, 1.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "r2".into(),
+                child: "b".into(),
             })
             .unwrap();
 
@@ -1379,64 +3050,213 @@ mod registry_tests {
             "static insert into a dynamic child frame must be rejected"
         );
 
-        // The registry state is untouched by the rejected insert.
-        let result = registry.get_transform("a", "b", t_dynamic);
-        assert_eq!(result.unwrap(), dynamic_tf);
+        // The registry state is untouched by the rejected insert.
+        let result = registry.get_transform("a", "b", t_dynamic);
+        assert_eq!(result.unwrap(), dynamic_tf);
+    }
+
+    #[test]
+    fn delete_transforms_before_removes_old_dynamic_transforms() {
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+
+        for &t in &[t1, t2] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "a".into(),
+                    child: "b".into(),
+                })
+                .unwrap();
+        }
+
+        registry.delete_transforms_before(Timestamp::from_nanos(2_000_000_000));
+
+        assert!(
+            registry.get_transform("a", "b", t1).is_err(),
+            "transforms before the cutoff must be deleted"
+        );
+        assert!(registry.get_transform("a", "b", t2).is_ok());
+    }
+
+    #[test]
+    fn delete_transforms_before_preserves_static_transforms() {
+        let mut registry = Registry::new();
+
+        let static_tf = Transform {
+            translation: Vector3::new(0.5, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::zero(),
+            parent: "base".into(),
+            child: "lidar".into(),
+        };
+        registry.add_transform(static_tf.clone()).unwrap();
+
+        // The documented manual-cleanup workflow must not destroy static
+        // transforms: they are valid for all time.
+        registry.delete_transforms_before(Timestamp::from_nanos(5_000_000_000));
+
+        let query = Timestamp::from_nanos(9_000_000_000);
+        let result = registry.get_transform("base", "lidar", query).unwrap();
+        assert_eq!(
+            result.translation, static_tf.translation,
+            "static transforms must survive manual cleanup"
+        );
+        // Lookup results carry the requested timestamp, not the static
+        // sentinel, so they compose with timestamped data.
+        assert_eq!(result.timestamp, query);
+    }
+
+    #[test]
+    fn delete_transforms_after_drops_future_data_and_empty_frames() {
+        let mut registry = Registry::new();
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(3_000_000_000);
+
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        for &t in &[t1, t2] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "map".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t2,
+                parent: "map".into(),
+                child: "object".into(),
+            })
+            .unwrap();
+
+        registry.delete_transforms_after(Timestamp::from_nanos(2_000_000_000));
+
+        assert!(registry.get_transform("map", "base", t1).is_ok());
+        assert!(registry.get_transform("map", "base", t2).is_err());
+        assert!(registry.get_transform("base", "lidar", t2).is_ok());
+
+        // "object" only held future data, so the frame is released and can
+        // come back under a new parent.
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.0, 0.5, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t1,
+                parent: "base".into(),
+                child: "object".into(),
+            })
+            .unwrap();
+        assert!(registry.get_transform("base", "object", t1).is_ok());
+    }
+
+    #[test]
+    fn retain_prunes_transforms_across_buffers() {
+        let mut registry = Registry::new();
+        let transform = |parent: &str, child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+        for tf in [
+            transform("map", "base", 1_000_000_000),
+            transform("map", "base", 2_000_000_000),
+            transform("base", "radar", 0),
+            transform("radar", "radar_optical", 0),
+            transform("base", "lidar", 0),
+        ] {
+            registry.add_transform(tf).unwrap();
+        }
+
+        let mut visited = Vec::new();
+        registry.retain(|parent, child, tf| {
+            visited.push(child.to_string());
+            parent != "base" || child != "radar" || tf.timestamp != Timestamp::zero()
+        });
+        assert_eq!(visited, ["base", "base", "lidar", "radar", "radar_optical"]);
+
+        // The emptied frame is gone; its child stays, detached.
+        assert!(registry.buffer("radar").is_none());
+        assert!(registry.buffer("radar_optical").is_some());
+        assert!(!registry.is_connected("base", "radar_optical"));
+        assert!(registry.is_connected("map", "lidar"));
+
+        registry.retain(|_, _, tf| tf.timestamp != Timestamp::from_nanos(2_000_000_000));
+        assert_eq!(registry.buffer("base").unwrap().len(), 1);
     }
 
     #[test]
-    fn delete_transforms_before_removes_old_dynamic_transforms() {
+    fn delete_transforms_between_removes_only_the_interval() {
         let mut registry = Registry::new();
-        let t1 = Timestamp::from_nanos(1_000_000_000);
-        let t2 = Timestamp::from_nanos(3_000_000_000);
+        let timestamps = [1, 2, 3].map(|s| Timestamp::from_nanos(s * 1_000_000_000));
 
-        for &t in &[t1, t2] {
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(0.5, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::zero(),
+                parent: "base".into(),
+                child: "lidar".into(),
+            })
+            .unwrap();
+        for &t in &timestamps {
             registry
                 .add_transform(Transform {
                     translation: Vector3::new(1.0, 0.0, 0.0),
                     rotation: Quaternion::identity(),
                     timestamp: t,
-                    parent: "a".into(),
-                    child: "b".into(),
+                    parent: "map".into(),
+                    child: "base".into(),
                 })
                 .unwrap();
         }
 
-        registry.delete_transforms_before(Timestamp::from_nanos(2_000_000_000));
+        registry.delete_transforms_between(timestamps[1], timestamps[1]);
 
-        assert!(
-            registry.get_transform("a", "b", t1).is_err(),
-            "transforms before the cutoff must be deleted"
+        assert!(registry.get_transform("map", "base", timestamps[0]).is_ok());
+        assert!(registry.get_transform("map", "base", timestamps[2]).is_ok());
+        // The removed sample at t=2 is now bridged by interpolation.
+        let bridged = registry
+            .get_transform("map", "base", timestamps[1])
+            .unwrap();
+        assert_eq!(bridged.timestamp, timestamps[1]);
+        assert_eq!(
+            registry
+                .get_transform("map", "lidar", timestamps[1])
+                .unwrap()
+                .translation,
+            Vector3::new(1.5, 0.0, 0.0)
         );
-        assert!(registry.get_transform("a", "b", t2).is_ok());
-    }
-
-    #[test]
-    fn delete_transforms_before_preserves_static_transforms() {
-        let mut registry = Registry::new();
-
-        let static_tf = Transform {
-            translation: Vector3::new(0.5, 0.0, 0.0),
-            rotation: Quaternion::identity(),
-            timestamp: Timestamp::zero(),
-            parent: "base".into(),
-            child: "lidar".into(),
-        };
-        registry.add_transform(static_tf.clone()).unwrap();
-
-        // The documented manual-cleanup workflow must not destroy static
-        // transforms: they are valid for all time.
-        registry.delete_transforms_before(Timestamp::from_nanos(5_000_000_000));
 
-        let query = Timestamp::from_nanos(9_000_000_000);
-        let result = registry.get_transform("base", "lidar", query).unwrap();
-        assert_eq!(
-            result.translation, static_tf.translation,
-            "static transforms must survive manual cleanup"
+        registry.delete_transforms_between(timestamps[0], timestamps[2]);
+        assert!(
+            registry
+                .get_transform("map", "base", timestamps[0])
+                .is_err()
+        );
+        assert!(
+            registry
+                .get_transform("base", "lidar", timestamps[0])
+                .is_ok()
         );
-        // Lookup results carry the requested timestamp, not the static
-        // sentinel, so they compose with timestamped data.
-        assert_eq!(result.timestamp, query);
     }
 
     #[test]
@@ -1559,6 +3379,284 @@ mod registry_tests {
         assert!(registry.get_transform("a", "b", t2).is_ok());
     }
 
+    #[test]
+    fn with_max_age_follows_inserted_time_not_a_clock() {
+        // Simulation time: stamps near zero, far behind any wall clock. Expiry
+        // must be measured against the newest inserted stamp, so nothing within
+        // max_age of it is dropped, however old it is in wall-clock terms.
+        let mut registry = Registry::with_max_age(Duration::from_secs(10));
+        for seconds in 1..=5 {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::from_nanos(seconds * 1_000_000_000),
+                    parent: "a".into(),
+                    child: "b".into(),
+                })
+                .unwrap();
+        }
+
+        assert_eq!(registry.buffer("b").unwrap().len(), 5);
+
+        // Advancing simulation time past max_age expires the oldest stamps.
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: Timestamp::from_nanos(13_000_000_000),
+                parent: "a".into(),
+                child: "b".into(),
+            })
+            .unwrap();
+
+        let buffer = registry.buffer("b").unwrap();
+        assert_eq!(buffer.oldest(), Some(Timestamp::from_nanos(3_000_000_000)));
+    }
+
+    #[test]
+    fn with_config_applies_max_entries_per_child_frame() {
+        let config = BufferConfig::new().with_max_entries(NonZeroUsize::new(1).unwrap());
+        let mut registry = Registry::with_config(config);
+
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+        for (parent, child) in [("a", "b"), ("b", "c")] {
+            for &t in &[t1, t2] {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: t,
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+
+        // Each child frame keeps its own newest sample; the limit is not
+        // shared across the registry.
+        assert!(registry.get_transform("a", "c", t1).is_err());
+        assert!(registry.get_transform("a", "c", t2).is_ok());
+    }
+
+    #[test]
+    fn builder_frame_max_age_overrides_default_for_that_frame() {
+        let mut registry = Registry::builder()
+            .default_max_age(Duration::from_secs(1))
+            .frame_max_age("odom", Duration::from_secs(10))
+            .build();
+
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(5_000_000_000);
+        for (parent, child) in [("map", "odom"), ("odom", "base")] {
+            for &t in &[t1, t2] {
+                registry
+                    .add_transform(Transform {
+                        translation: Vector3::new(1.0, 0.0, 0.0),
+                        rotation: Quaternion::identity(),
+                        timestamp: t,
+                        parent: parent.into(),
+                        child: child.into(),
+                    })
+                    .unwrap();
+            }
+        }
+
+        // "odom" keeps its four-second-old sample; "base" uses the one-second
+        // default and has already dropped it.
+        assert!(registry.get_transform("map", "odom", t1).is_ok());
+        assert!(registry.get_transform("odom", "base", t1).is_err());
+        assert!(registry.get_transform("odom", "base", t2).is_ok());
+    }
+
+    #[test]
+    fn clear_on_time_jump_drops_dynamic_history_and_keeps_statics() {
+        let mut registry = Registry::builder()
+            .clear_on_time_jump(Duration::from_secs(1))
+            .build();
+        let transform = |parent: &str, child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: parent.into(),
+            child: child.into(),
+        };
+
+        registry
+            .add_transform(transform("base", "lidar", 0))
+            .unwrap();
+        for nanos in [9_000_000_000, 10_000_000_000] {
+            registry
+                .add_transform(transform("map", "base", nanos))
+                .unwrap();
+            registry
+                .add_transform(transform("map", "odom", nanos))
+                .unwrap();
+        }
+
+        // Out-of-order data within the threshold is no jump.
+        registry
+            .add_transform(transform("map", "base", 9_500_000_000))
+            .unwrap();
+        assert_eq!(registry.buffer("base").unwrap().len(), 3);
+        assert!(registry.buffer("base").unwrap().rate().is_some());
+
+        // A rewind beyond the threshold clears every dynamic buffer, and
+        // the rate of the discarded history with it.
+        registry
+            .add_transform(transform("map", "base", 2_000_000_000))
+            .unwrap();
+        assert_eq!(registry.buffer("base").unwrap().len(), 1);
+        assert_eq!(registry.buffer("base").unwrap().rate(), None);
+        assert!(registry.buffer("odom").is_none());
+        assert!(
+            registry
+                .get_transform("base", "lidar", Timestamp::from_nanos(2_000_000_000))
+                .is_ok()
+        );
+
+        // New statics never count as a jump.
+        registry
+            .add_transform(transform("base", "camera", 0))
+            .unwrap();
+        assert_eq!(registry.buffer("base").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rejected_time_jump_keeps_dynamic_history() {
+        let mut registry = Registry::builder()
+            .clear_on_time_jump(Duration::from_secs(1))
+            .build();
+        let t = Timestamp::from_nanos(10_000_000_000);
+        registry
+            .add_transform(Transform {
+                translation: Vector3::new(1.0, 0.0, 0.0),
+                rotation: Quaternion::identity(),
+                timestamp: t,
+                parent: "map".into(),
+                child: "base".into(),
+            })
+            .unwrap();
+
+        // A time-jumped insert that fails validation discards nothing.
+        let result = registry.add_transform(Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::new(2.0, 0.0, 0.0, 0.0),
+            timestamp: Timestamp::from_nanos(2_000_000_000),
+            parent: "map".into(),
+            child: "odom".into(),
+        });
+        assert!(matches!(result, Err(BufferError::TransformError(_))));
+        assert_eq!(registry.buffer("base").unwrap().len(), 1);
+        assert!(registry.get_transform("map", "base", t).is_ok());
+        assert!(registry.buffer("odom").is_none());
+    }
+
+    #[test]
+    fn clear_on_time_jump_follows_removed_history() {
+        let mut registry = Registry::builder()
+            .clear_on_time_jump(Duration::from_secs(1))
+            .build();
+        let transform = |child: &str, nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: "map".into(),
+            child: child.into(),
+        };
+        registry
+            .add_transform(transform("base", 2_000_000_000))
+            .unwrap();
+        registry
+            .add_transform(transform("odom", 10_000_000_000))
+            .unwrap();
+
+        // Once the newest sample is gone, an insert near the remaining
+        // history is no jump.
+        registry.remove_frame("odom");
+        registry
+            .add_transform(transform("base", 2_500_000_000))
+            .unwrap();
+        assert_eq!(registry.buffer("base").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn without_clear_on_time_jump_rewinds_are_stored() {
+        let mut registry = Registry::new();
+        for nanos in [10_000_000_000, 2_000_000_000] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: Timestamp::from_nanos(nanos),
+                    parent: "map".into(),
+                    child: "base".into(),
+                })
+                .unwrap();
+        }
+
+        assert_eq!(registry.buffer("base").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn builder_default_settings_follow_call_order() {
+        let transform = |nanos| Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            parent: "a".into(),
+            child: "b".into(),
+        };
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let max_age = Duration::from_millis(500);
+
+        // A later default_config replaces the earlier default_max_age.
+        let mut replaced = Registry::builder()
+            .default_max_age(max_age)
+            .default_config(BufferConfig::new())
+            .build();
+        // A later default_max_age refines the earlier default_config.
+        let mut refined = Registry::builder()
+            .default_config(BufferConfig::new())
+            .default_max_age(max_age)
+            .build();
+        for registry in [&mut replaced, &mut refined] {
+            for nanos in [1_000_000_000, 2_000_000_000] {
+                registry.add_transform(transform(nanos)).unwrap();
+            }
+        }
+
+        assert!(replaced.get_transform("a", "b", t1).is_ok());
+        assert!(refined.get_transform("a", "b", t1).is_err());
+    }
+
+    #[test]
+    fn builder_frame_max_age_keeps_other_default_settings() {
+        let mut registry = Registry::builder()
+            .default_max_entries(NonZeroUsize::new(1).unwrap())
+            .frame_max_age("b", Duration::from_secs(10))
+            .build();
+
+        let t1 = Timestamp::from_nanos(1_000_000_000);
+        let t2 = Timestamp::from_nanos(2_000_000_000);
+        for &t in &[t1, t2] {
+            registry
+                .add_transform(Transform {
+                    translation: Vector3::new(1.0, 0.0, 0.0),
+                    rotation: Quaternion::identity(),
+                    timestamp: t,
+                    parent: "a".into(),
+                    child: "b".into(),
+                })
+                .unwrap();
+        }
+
+        assert!(registry.get_transform("a", "b", t1).is_err());
+        assert!(registry.get_transform("a", "b", t2).is_ok());
+    }
+
     #[test]
     fn failed_insert_does_not_bypass_cycle_detection() {
         let mut registry = Registry::new();
@@ -1685,6 +3783,155 @@ mod registry_tests {
         assert_eq!(point.position, Vector3::new(1.0, 1.0, 0.0));
     }
 
+    fn edge(
+        parent: &str,
+        child: &str,
+        translation: Vector3,
+        timestamp: Timestamp,
+    ) -> Transform {
+        Transform {
+            translation,
+            rotation: Quaternion::identity(),
+            timestamp,
+            parent: parent.into(),
+            child: child.into(),
+        }
+    }
+
+    #[test]
+    fn registry_group_composes_across_registries() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let mut calibration = Registry::new();
+        calibration
+            .add_static_transform(edge(
+                "base",
+                "lidar",
+                Vector3::new(0.0, 0.0, 0.5),
+                Timestamp::zero(),
+            ))
+            .unwrap();
+        calibration
+            .add_static_transform(edge(
+                "base",
+                "camera",
+                Vector3::new(0.0, 1.0, 0.0),
+                Timestamp::zero(),
+            ))
+            .unwrap();
+        let mut odometry = Registry::new();
+        odometry
+            .add_transform(edge("odom", "base", Vector3::new(2.0, 0.0, 0.0), t))
+            .unwrap();
+
+        let group = RegistryGroup::new([&calibration, &odometry]).unwrap();
+        assert_eq!(group.registries().len(), 2);
+
+        let lidar = group.get_transform("odom", "lidar", t).unwrap();
+        assert_eq!(lidar.parent, "odom");
+        assert_eq!(lidar.child, "lidar");
+        assert_eq!(lidar.timestamp, t);
+        assert_eq!(lidar.translation, Vector3::new(2.0, 0.0, 0.5));
+
+        let inverse = group.get_transform("lidar", "odom", t).unwrap();
+        assert_eq!(inverse.translation, Vector3::new(-2.0, 0.0, -0.5));
+
+        let sibling = group.get_transform("lidar", "camera", t).unwrap();
+        assert_eq!(sibling.translation, Vector3::new(0.0, 1.0, -0.5));
+
+        let identity = group.get_transform("lidar", "lidar", t).unwrap();
+        assert_eq!(identity.translation, Vector3::zero());
+    }
+
+    #[test]
+    fn registry_group_prefers_earlier_registries() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let mut primary = Registry::new();
+        primary
+            .add_transform(edge("a", "b", Vector3::new(1.0, 0.0, 0.0), t))
+            .unwrap();
+        let mut fallback = Registry::new();
+        fallback
+            .add_transform(edge("a", "b", Vector3::new(5.0, 0.0, 0.0), t))
+            .unwrap();
+
+        let group = RegistryGroup::new([&primary, &fallback]).unwrap();
+        let result = group.get_transform("a", "b", t).unwrap();
+        assert_eq!(result.translation, Vector3::new(1.0, 0.0, 0.0));
+
+        let group = RegistryGroup::new([&fallback, &primary]).unwrap();
+        let result = group.get_transform("a", "b", t).unwrap();
+        assert_eq!(result.translation, Vector3::new(5.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn registry_group_rejects_inconsistent_trees() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let mut first = Registry::new();
+        first
+            .add_transform(edge("a", "b", Vector3::zero(), t))
+            .unwrap();
+        let mut reparented = Registry::new();
+        reparented
+            .add_transform(edge("c", "b", Vector3::zero(), t))
+            .unwrap();
+        assert!(matches!(
+            RegistryGroup::new([&first, &reparented]),
+            Err(BufferError::ReparentingNotSupported(parent)) if parent == "a"
+        ));
+
+        let mut cyclic = Registry::new();
+        cyclic
+            .add_transform(edge("b", "a", Vector3::zero(), t))
+            .unwrap();
+        assert!(matches!(
+            RegistryGroup::new([&first, &cyclic]),
+            Err(BufferError::CycleDetected)
+        ));
+    }
+
+    #[test]
+    fn registry_group_reports_why_a_lookup_fails() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let mut first = Registry::new();
+        first
+            .add_transform(edge("a", "b", Vector3::zero(), t))
+            .unwrap();
+        let mut second = Registry::new();
+        second
+            .add_transform(edge("x", "y", Vector3::zero(), t))
+            .unwrap();
+        let group = RegistryGroup::new([&first, &second]).unwrap();
+
+        assert!(matches!(
+            group.get_transform("a", "missing", t),
+            Err(TransformError::UnknownFrame(frame)) if frame == "missing"
+        ));
+        assert!(matches!(
+            group.get_transform("b", "y", t),
+            Err(TransformError::Disconnected(from, to)) if from == "b" && to == "y"
+        ));
+        assert!(matches!(
+            group.get_transform("a", "b", Timestamp::from_nanos(5_000_000_000)),
+            Err(TransformError::NotFoundAt { frame, .. }) if frame == "b"
+        ));
+    }
+
+    #[test]
+    fn registry_group_resolves_remapped_frames() {
+        let t = Timestamp::from_nanos(1_000_000_000);
+        let mut first = Registry::new();
+        first
+            .add_transform(edge("map", "base", Vector3::new(1.0, 0.0, 0.0), t))
+            .unwrap();
+        let mut aliases = Registry::new();
+        aliases.remap_frame("base_link", "base");
+
+        let group = RegistryGroup::new([&first, &aliases]).unwrap();
+        let result = group.get_transform("map", "base_link", t).unwrap();
+        assert_eq!(result.child, "base");
+        assert_eq!(result.translation, Vector3::new(1.0, 0.0, 0.0));
+    }
+
     #[test]
     fn public_types_are_send_and_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
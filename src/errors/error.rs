@@ -0,0 +1,83 @@
+use thiserror::Error;
+
+use crate::errors::{BufferError, QuaternionError, TimeError, TransformError};
+
+/// Any error produced by this crate.
+///
+/// Each operation returns its specific error type; `Error` unifies them
+/// for application code that mixes operations and propagates failures with
+/// `?`. The `From` impls convert every specific error, and the wrapped
+/// error is displayed and reported as the source unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Registry,
+///     geometry::{Quaternion, Transform, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// fn sensor_in_base(registry: &mut Registry) -> transforms::Result<Vector3> {
+///     // `add_transform` fails with a `BufferError`, `get_transform` with a
+///     // `TransformError`; both convert into `transforms::Error`.
+///     registry.add_transform(Transform {
+///         translation: Vector3::new(0.5, 0.0, 0.2),
+///         rotation: Quaternion::identity(),
+///         timestamp: Timestamp::zero(),
+///         parent: "base".into(),
+///         child: "sensor".into(),
+///     })?;
+///     let transform = registry.get_transform("base", "sensor", Timestamp::zero())?;
+///     Ok(transform.translation)
+/// }
+///
+/// let mut registry = Registry::new();
+/// assert_eq!(
+///     sensor_in_base(&mut registry).unwrap(),
+///     Vector3::new(0.5, 0.0, 0.2)
+/// );
+/// ```
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A transform lookup, composition, or application failed.
+    #[error(transparent)]
+    Transform(#[from] TransformError),
+
+    /// A buffer or registry insertion or retrieval failed.
+    #[error(transparent)]
+    Buffer(#[from] BufferError),
+
+    /// A quaternion operation failed.
+    #[error(transparent)]
+    Quaternion(#[from] QuaternionError),
+
+    /// A timestamp operation failed.
+    #[error(transparent)]
+    Time(#[from] TimeError),
+}
+
+impl Error {
+    /// Returns the stable numeric code of the wrapped error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::errors::{BufferError, Error};
+    ///
+    /// assert_eq!(Error::from(BufferError::CycleDetected).code(), 205);
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::Transform(error) => error.code(),
+            Self::Buffer(error) => error.code(),
+            Self::Quaternion(error) => error.code(),
+            Self::Time(error) => error.code(),
+        }
+    }
+}
+
+/// A `Result` defaulting to the crate-wide [`Error`](enum@Error).
+pub type Result<T, E = Error> = core::result::Result<T, E>;
@@ -1,7 +1,28 @@
 //! Re-exports of all error types in this crate.
+//!
+//! Every error enum is `#[non_exhaustive]` and implements
+//! [`core::error::Error`], also without `std`. Each variant has a stable
+//! numeric code, returned by `code()`, grouped by enum:
+//!
+//! | Codes | Enum |
+//! |-------|------|
+//! | 100–199 | [`TransformError`] |
+//! | 200–299 | [`BufferError`] |
+//! | 300–399 | [`QuaternionError`] |
+//! | 400–499 | [`TimeError`] |
+//!
+//! [`Error`](enum@Error) wraps all of them for application code that
+//! propagates mixed failures with `?`, and [`Result`] defaults to it.
+
+mod error;
+
+pub use error::{Error, Result};
 
 pub use crate::{
     core::buffer::BufferError,
     geometry::{quaternion::QuaternionError, transform::TransformError},
     time::TimeError,
 };
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod errors_tests {
+    use crate::errors::{BufferError, Error, QuaternionError, TimeError, TransformError};
+    use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+    fn assert_error<E: core::error::Error>() {}
+
+    #[test]
+    fn error_enums_implement_core_error() {
+        assert_error::<Error>();
+        assert_error::<TransformError>();
+        assert_error::<BufferError>();
+        assert_error::<QuaternionError>();
+        assert_error::<TimeError>();
+    }
+
+    #[test]
+    fn codes_are_unique_and_grouped_by_enum() {
+        let transform = [
+            TransformError::NonUnitRotation(2.0),
+            TransformError::NonFiniteValues,
+            TransformError::TimestampMismatch(1.0, 2.0),
+            TransformError::ZeroVelocityWindow,
+            TransformError::TimestampOutOfRange(0.0, 1.0, 2.0),
+            TransformError::SameFrameMultiplication,
+            TransformError::IncompatibleFrames,
+            TransformError::UnknownFrame("a".into()),
+            TransformError::Disconnected("a".into(), "b".into()),
+            TransformError::NotFoundAt {
+                from: "a".into(),
+                to: "b".into(),
+                frame: "b".into(),
+                source: Box::new(BufferError::NoTransformAvailable),
+            },
+            TransformError::TransformTreeEmpty,
+            TransformError::TimestampError(TimeError::AccuracyLoss),
+            TransformError::QuaternionError(QuaternionError::NonFinite),
+        ]
+        .map(|error| error.code());
+        let buffer = [
+            BufferError::NoTransformAvailable,
+            BufferError::StaticDynamicConflict,
+            BufferError::SelfReferentialFrame,
+            BufferError::ReparentingNotSupported("a".into()),
+            BufferError::ChildFrameMismatch("a".into()),
+            BufferError::CycleDetected,
+            BufferError::NoExactMatch(1.0),
+            BufferError::NoSampleWithinTolerance(1.0),
+            BufferError::UnknownFrame("a".into()),
+            BufferError::FrameExists("a".into()),
+            BufferError::TransformError(TransformError::IncompatibleFrames),
+            BufferError::TransformOlderThanLatest(1.0, 2.0),
+            BufferError::InterpolationGapTooLarge(1.5, 1.0, 2.0),
+        ]
+        .map(|error| error.code());
+        let quaternion = [
+            QuaternionError::DivisionByZero,
+            QuaternionError::ZeroLengthNormalization,
+            QuaternionError::NonFinite,
+            QuaternionError::ZeroLengthVector,
+            QuaternionError::NotARotationMatrix,
+            QuaternionError::InvalidWeight,
+            QuaternionError::AmbiguousAverage,
+        ]
+        .map(|error| error.code());
+        let time = [
+            TimeError::DurationUnderflow,
+            TimeError::DurationOverflow,
+            TimeError::AccuracyLoss,
+            TimeError::NonFinite,
+        ]
+        .map(|error| error.code());
+
+        assert!(transform.iter().all(|code| (100..200).contains(code)));
+        assert!(buffer.iter().all(|code| (200..300).contains(code)));
+        assert!(quaternion.iter().all(|code| (300..400).contains(code)));
+        assert!(time.iter().all(|code| (400..500).contains(code)));
+
+        let mut all: Vec<u16> = [&transform[..], &buffer[..], &quaternion[..], &time[..]].concat();
+        let count = all.len();
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), count, "error codes must be unique");
+    }
+
+    #[test]
+    fn codes_are_stable() {
+        // Published codes must never change; extend, do not renumber.
+        assert_eq!(TransformError::NonUnitRotation(2.0).code(), 100);
+        assert_eq!(
+            TransformError::QuaternionError(QuaternionError::NonFinite).code(),
+            112
+        );
+        assert_eq!(BufferError::NoTransformAvailable.code(), 200);
+        assert_eq!(
+            BufferError::TransformError(TransformError::NonFiniteValues).code(),
+            210
+        );
+        assert_eq!(QuaternionError::NotARotationMatrix.code(), 304);
+        assert_eq!(TimeError::NonFinite.code(), 403);
+    }
+
+    #[test]
+    fn error_wraps_every_error_type_transparently() {
+        let errors: [Error; 4] = [
+            TransformError::IncompatibleFrames.into(),
+            BufferError::CycleDetected.into(),
+            QuaternionError::NonFinite.into(),
+            TimeError::DurationOverflow.into(),
+        ];
+
+        assert!(matches!(
+            errors[0],
+            Error::Transform(TransformError::IncompatibleFrames)
+        ));
+        assert!(matches!(
+            errors[1],
+            Error::Buffer(BufferError::CycleDetected)
+        ));
+        assert!(matches!(
+            errors[2],
+            Error::Quaternion(QuaternionError::NonFinite)
+        ));
+        assert!(matches!(
+            errors[3],
+            Error::Time(TimeError::DurationOverflow)
+        ));
+
+        assert_eq!(errors.each_ref().map(Error::code), [106, 205, 302, 401]);
+        assert_eq!(
+            errors[1].to_string(),
+            BufferError::CycleDetected.to_string()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Float math that works with and without `std`.
+//!
+//! `f64::sqrt`, `sin`, `cos`, `asin`, `acos`, and `atan2` are `std` methods
+//! rather than `core` intrinsics; without `std` the equivalent `libm`
+//! implementations are used.
+
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrt(x)
+    }
+}
+
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sin()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sin(x)
+    }
+}
+
+#[inline]
+pub(crate) fn cos(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.cos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::cos(x)
+    }
+}
+
+#[inline]
+pub(crate) fn asin(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.asin()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::asin(x)
+    }
+}
+
+#[inline]
+pub(crate) fn acos(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.acos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::acos(x)
+    }
+}
+
+#[inline]
+pub(crate) fn atan2(
+    y: f64,
+    x: f64,
+) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        y.atan2(x)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::atan2(y, x)
+    }
+}
@@ -1,11 +1,20 @@
-//! Geometric primitives: transforms, vectors, quaternions, and an example transformable Point type.
+//! Geometric primitives: 3D and planar transforms, vectors, quaternions, and transformable types such as points, poses, and twists.
 
+pub(crate) mod math;
 pub mod point;
+pub mod pose_with_covariance;
+pub mod position;
 pub mod quaternion;
 pub mod transform;
+pub mod transform2d;
+pub mod twist;
 pub mod vector3;
 
 pub use point::Point;
-pub use quaternion::Quaternion;
+pub use pose_with_covariance::PoseWithCovariance;
+pub use position::Position;
+pub use quaternion::{EulerOrder, Quaternion};
 pub use transform::{Localized, Transform, Transformable};
+pub use transform2d::Transform2d;
+pub use twist::Twist;
 pub use vector3::Vector3;
@@ -116,6 +116,83 @@ where
     }
 }
 
+/// Transforms a whole slice of points, such as a point cloud, in one call.
+///
+/// Every point is checked as for a single [`Point`] before any is changed,
+/// and the rotation matrix is computed once for the slice instead of a
+/// quaternion sandwich product per point. `Vec<Point>` can call it
+/// directly through deref.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Transform, Transformable,
+///     geometry::{Point, Quaternion, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let mut cloud: Vec<Point> = (0..3)
+///     .map(|i| Point {
+///         position: Vector3::new(f64::from(i), 0.0, 0.0),
+///         orientation: Quaternion::identity(),
+///         timestamp: Timestamp::zero(),
+///         frame: "lidar".into(),
+///     })
+///     .collect();
+///
+/// let mount = Transform {
+///     translation: Vector3::new(0.0, 0.0, 1.5),
+///     rotation: Quaternion::identity(),
+///     timestamp: Timestamp::zero(),
+///     parent: "base".into(),
+///     child: "lidar".into(),
+/// };
+///
+/// cloud.transform(&mount).unwrap();
+/// assert!(
+///     cloud
+///         .iter()
+///         .all(|p| p.frame == "base" && p.position.z == 1.5)
+/// );
+/// ```
+impl<T> Transformable<T> for [Point<T>]
+where
+    T: TimePoint,
+{
+    /// Applies a transformation to every point of the slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransformError`] if any point's frame does not match the
+    /// transform's child frame, or if any timestamp does not match; the
+    /// slice is left unchanged. Static transforms apply to points of any
+    /// timestamp.
+    fn transform(
+        &mut self,
+        transform: &Transform<T>,
+    ) -> Result<(), TransformError> {
+        for point in self.iter() {
+            if point.frame != transform.child {
+                return Err(TransformError::IncompatibleFrames);
+            }
+            if point.timestamp != transform.timestamp && !transform.timestamp.is_static() {
+                return Err(TransformError::TimestampMismatch(
+                    point.timestamp.as_seconds_lossy(),
+                    transform.timestamp.as_seconds_lossy(),
+                ));
+            }
+        }
+        let matrix = transform.rotation.to_rotation_matrix();
+        for point in self {
+            point.position = point.position.rotate_by_matrix(&matrix) + transform.translation;
+            point.orientation = transform.rotation * point.orientation;
+            point.frame.clone_from(&transform.parent);
+        }
+        Ok(())
+    }
+}
+
 /// The `Localized` trait provides frame and timestamp introspection for a `Point`,
 /// enabling automatic transform lookup via
 /// [`Registry::get_transform_for`](crate::core::Registry::get_transform_for).
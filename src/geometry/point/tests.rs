@@ -2,9 +2,11 @@
 mod point_tests {
     use crate::{
         Transform, Transformable,
+        errors::TransformError,
         geometry::{Point, Quaternion, Vector3},
         time::Timestamp,
     };
+    use alloc::vec::Vec;
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -20,7 +22,7 @@ mod point_tests {
     #[test]
     fn transform_rotates_orientation() {
         let theta = core::f64::consts::PI / 2.0;
-        let rot_z_90 = Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin());
+        let rot_z_90 = Quaternion::from_rotation_z(theta);
 
         let mut point = Point {
             position: Vector3::new(1.0, 0.0, 0.0),
@@ -49,4 +51,59 @@ mod point_tests {
         };
         assert_abs_diff_eq!(point, expected, epsilon = 1e-10);
     }
+
+    #[test]
+    fn transform_slice_matches_per_point_transform() {
+        let rotation = Quaternion::new(0.9, 0.1, -0.3, 0.2).normalize().unwrap();
+        let transform = Transform {
+            translation: Vector3::new(1.0, -2.0, 0.5),
+            rotation,
+            timestamp: Timestamp::from_nanos(1_000),
+            parent: "base".into(),
+            child: "lidar".into(),
+        };
+        let mut cloud: Vec<Point> = [(1.0, 0.0, 0.0), (0.0, 2.0, -1.0), (-3.0, 0.5, 4.0)]
+            .into_iter()
+            .map(|(x, y, z)| Point {
+                position: Vector3::new(x, y, z),
+                orientation: Quaternion::identity(),
+                timestamp: Timestamp::from_nanos(1_000),
+                frame: "lidar".into(),
+            })
+            .collect();
+        let mut expected = cloud.clone();
+        for point in &mut expected {
+            point.transform(&transform).unwrap();
+        }
+
+        cloud.transform(&transform).unwrap();
+
+        for (point, expected) in cloud.iter().zip(&expected) {
+            assert_abs_diff_eq!(point, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn transform_slice_is_unchanged_on_error() {
+        let transform = Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(1_000),
+            parent: "base".into(),
+            child: "lidar".into(),
+        };
+        let point = |nanos| Point {
+            position: Vector3::zero(),
+            orientation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(nanos),
+            frame: "lidar".into(),
+        };
+        let mut cloud = [point(1_000), point(2_000)];
+
+        assert!(matches!(
+            cloud.transform(&transform),
+            Err(TransformError::TimestampMismatch(_, _))
+        ));
+        assert_eq!(cloud, [point(1_000), point(2_000)]);
+    }
 }
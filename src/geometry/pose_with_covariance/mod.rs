@@ -0,0 +1,240 @@
+//! Poses that carry their uncertainty.
+
+use crate::{
+    Localized, Transform, Transformable,
+    errors::TransformError,
+    geometry::{Quaternion, Vector3},
+    time::{TimePoint, Timestamp},
+};
+
+use alloc::string::String;
+use approx::{AbsDiffEq, RelativeEq};
+
+/// A pose with a 6x6 covariance, as produced by localization and SLAM.
+///
+/// The covariance rows and columns are ordered `(x, y, z, rx, ry, rz)`, the
+/// position followed by rotations about the fixed x, y and z axes of
+/// `frame`, the same layout as ROS's `PoseWithCovariance`.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     geometry::{PoseWithCovariance, Quaternion, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let mut covariance = [[0.0; 6]; 6];
+/// covariance[0][0] = 0.25;
+///
+/// let pose = PoseWithCovariance {
+///     position: Vector3::new(1.0, 2.0, 3.0),
+///     orientation: Quaternion::identity(),
+///     covariance,
+///     timestamp: Timestamp::zero(),
+///     frame: "map".into(),
+/// };
+///
+/// assert_eq!(pose.covariance[0][0], 0.25);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseWithCovariance<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The 3D position of the pose.
+    pub position: Vector3,
+    /// The orientation of the pose.
+    pub orientation: Quaternion,
+    /// The row-major covariance over `(x, y, z, rx, ry, rz)`.
+    pub covariance: [[f64; 6]; 6],
+    /// The time the pose was estimated at.
+    pub timestamp: T,
+    /// The reference frame of the pose and its covariance.
+    pub frame: String,
+}
+
+/// Expresses a pose and its covariance in the parent frame of a transform.
+///
+/// The pose is transformed like a [`Point`](crate::geometry::Point). The
+/// covariance is rotated into the parent frame: with `R` the transform's
+/// rotation and `J = diag(R, R)`, it becomes `J * covariance * Jᵀ`. The
+/// transform itself is treated as exact, so the uncertainty neither grows
+/// nor depends on the translation.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Transform, Transformable,
+///     geometry::{PoseWithCovariance, Quaternion, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// // Uncertain only along the sensor's x-axis.
+/// let mut covariance = [[0.0; 6]; 6];
+/// covariance[0][0] = 1.0;
+/// let mut pose = PoseWithCovariance {
+///     position: Vector3::zero(),
+///     orientation: Quaternion::identity(),
+///     covariance,
+///     timestamp: Timestamp::zero(),
+///     frame: "sensor".into(),
+/// };
+///
+/// // The sensor is yawed by 90 degrees in the base frame.
+/// let mount = Transform {
+///     translation: Vector3::new(1.0, 0.0, 0.0),
+///     rotation: Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2),
+///     timestamp: Timestamp::zero(),
+///     parent: "base".into(),
+///     child: "sensor".into(),
+/// };
+///
+/// pose.transform(&mount).unwrap();
+/// assert_eq!(pose.frame, "base");
+/// // The uncertainty now lies along the base y-axis.
+/// assert!((pose.covariance[1][1] - 1.0).abs() < 1e-12);
+/// assert!(pose.covariance[0][0].abs() < 1e-12);
+/// ```
+impl<T> Transformable<T> for PoseWithCovariance<T>
+where
+    T: TimePoint,
+{
+    /// Applies a transformation to the pose, rotating its covariance into
+    /// the new frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransformError`] if the pose's frame does not match the
+    /// transform's child frame, or if the timestamps do not match. Static
+    /// transforms (carrying the static timestamp value) are valid for all
+    /// time and apply to a pose of any timestamp.
+    fn transform(
+        &mut self,
+        transform: &Transform<T>,
+    ) -> Result<(), TransformError> {
+        if self.frame != transform.child {
+            return Err(TransformError::IncompatibleFrames);
+        }
+        if self.timestamp != transform.timestamp && !transform.timestamp.is_static() {
+            return Err(TransformError::TimestampMismatch(
+                self.timestamp.as_seconds_lossy(),
+                transform.timestamp.as_seconds_lossy(),
+            ));
+        }
+        self.position = transform.rotation.rotate_vector(self.position) + transform.translation;
+        self.orientation = transform.rotation * self.orientation;
+        self.covariance = rotate_covariance(transform.rotation, &self.covariance);
+        self.frame.clone_from(&transform.parent);
+        Ok(())
+    }
+}
+
+impl<T> Localized<T> for PoseWithCovariance<T>
+where
+    T: TimePoint,
+{
+    fn frame(&self) -> &str {
+        &self.frame
+    }
+
+    fn timestamp(&self) -> T {
+        self.timestamp
+    }
+}
+
+/// Pairs up the entries of two covariances.
+fn covariance_entries<'a>(
+    a: &'a [[f64; 6]; 6],
+    b: &'a [[f64; 6]; 6],
+) -> impl Iterator<Item = (&'a f64, &'a f64)> {
+    a.iter().flatten().zip(b.iter().flatten())
+}
+
+/// Computes `J * covariance * Jᵀ` for `J = diag(R, R)`.
+fn rotate_covariance(
+    rotation: Quaternion,
+    covariance: &[[f64; 6]; 6],
+) -> [[f64; 6]; 6] {
+    let [[r00, r01, r02], [r10, r11, r12], [r20, r21, r22]] = rotation.to_rotation_matrix();
+    let jacobian = [
+        [r00, r01, r02, 0.0, 0.0, 0.0],
+        [r10, r11, r12, 0.0, 0.0, 0.0],
+        [r20, r21, r22, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, r00, r01, r02],
+        [0.0, 0.0, 0.0, r10, r11, r12],
+        [0.0, 0.0, 0.0, r20, r21, r22],
+    ];
+    let dot = |a: &[f64; 6], b: &[f64; 6]| a.iter().zip(b).map(|(a, b)| a * b).sum::<f64>();
+
+    // `left` is J * covariance, row by row.
+    let left = jacobian.map(|j_row| {
+        let mut row = [0.0; 6];
+        for (j, covariance_row) in j_row.iter().zip(covariance) {
+            for (value, c) in row.iter_mut().zip(covariance_row) {
+                *value += j * c;
+            }
+        }
+        row
+    });
+    left.map(|left_row| jacobian.map(|j_row| dot(&left_row, &j_row)))
+}
+
+impl<T> AbsDiffEq for PoseWithCovariance<T>
+where
+    T: TimePoint,
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares position, orientation and every covariance entry within
+    /// `epsilon`. The frame and timestamp are compared exactly.
+    fn abs_diff_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+    ) -> bool {
+        self.position.abs_diff_eq(&other.position, epsilon)
+            && self.orientation.abs_diff_eq(&other.orientation, epsilon)
+            && covariance_entries(&self.covariance, &other.covariance)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            && self.timestamp == other.timestamp
+            && self.frame == other.frame
+    }
+}
+
+impl<T> RelativeEq for PoseWithCovariance<T>
+where
+    T: TimePoint,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares position, orientation and every covariance entry with a
+    /// relative tolerance. The frame and timestamp are compared exactly.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.position
+            .relative_eq(&other.position, epsilon, max_relative)
+            && self
+                .orientation
+                .relative_eq(&other.orientation, epsilon, max_relative)
+            && covariance_entries(&self.covariance, &other.covariance)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            && self.timestamp == other.timestamp
+            && self.frame == other.frame
+    }
+}
+
+#[cfg(test)]
+mod tests;
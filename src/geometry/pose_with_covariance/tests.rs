@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod pose_with_covariance_tests {
+    use crate::{
+        Localized, Transform, Transformable,
+        errors::TransformError,
+        geometry::{PoseWithCovariance, Quaternion, Vector3},
+        time::Timestamp,
+    };
+    use approx::assert_abs_diff_eq;
+
+    fn diagonal(values: [f64; 6]) -> [[f64; 6]; 6] {
+        let mut covariance = [[0.0; 6]; 6];
+        for (i, value) in values.into_iter().enumerate() {
+            covariance[i][i] = value;
+        }
+        covariance
+    }
+
+    fn pose(frame: &str) -> PoseWithCovariance {
+        PoseWithCovariance {
+            position: Vector3::new(1.0, 0.0, 0.0),
+            orientation: Quaternion::identity(),
+            covariance: diagonal([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: frame.into(),
+        }
+    }
+
+    #[test]
+    fn transform_rotates_pose_and_covariance() {
+        let rotation = Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2);
+        let mut pose = pose("sensor");
+
+        pose.transform(&Transform {
+            translation: Vector3::new(0.0, 0.0, 5.0),
+            rotation,
+            timestamp: Timestamp::zero(),
+            parent: "base".into(),
+            child: "sensor".into(),
+        })
+        .unwrap();
+
+        assert_abs_diff_eq!(pose.position, Vector3::new(0.0, 1.0, 5.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(pose.orientation, rotation, epsilon = 1e-12);
+        // A yaw of 90 degrees swaps the x and y variances, both for the
+        // position and the rotation block.
+        let expected = diagonal([2.0, 1.0, 3.0, 5.0, 4.0, 6.0]);
+        for (row, expected_row) in pose.covariance.iter().zip(expected.iter()) {
+            for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+                assert_abs_diff_eq!(value, expected_value, epsilon = 1e-12);
+            }
+        }
+        assert_eq!(pose.frame(), "base");
+        assert_eq!(pose.timestamp(), Timestamp::from_nanos(1_000));
+    }
+
+    #[test]
+    fn transform_rotates_correlations() {
+        let mut pose = pose("sensor");
+        pose.covariance = [[0.0; 6]; 6];
+        pose.covariance[0][0] = 1.0;
+
+        // Rotating a variance along x by 45 degrees spreads it over x and y.
+        pose.transform(&Transform {
+            translation: Vector3::zero(),
+            rotation: Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_4),
+            timestamp: Timestamp::from_nanos(1_000),
+            parent: "base".into(),
+            child: "sensor".into(),
+        })
+        .unwrap();
+
+        for (i, j) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            assert_abs_diff_eq!(pose.covariance[i][j], 0.5, epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(pose.covariance[2][2], 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn transform_rejects_mismatched_frames_and_timestamps() {
+        let tf = Transform {
+            translation: Vector3::zero(),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(2_000),
+            parent: "base".into(),
+            child: "sensor".into(),
+        };
+
+        let mut other_frame = pose("camera");
+        assert!(matches!(
+            other_frame.transform(&tf),
+            Err(TransformError::IncompatibleFrames)
+        ));
+        let mut other_time = pose("sensor");
+        assert!(matches!(
+            other_time.transform(&tf),
+            Err(TransformError::TimestampMismatch(_, _))
+        ));
+        assert_eq!(other_time, pose("sensor"));
+    }
+
+    #[test]
+    fn approx_comparison_includes_the_covariance() {
+        let a = pose("sensor");
+        let mut b = a.clone();
+        b.covariance[3][3] += 1e-9;
+
+        assert!(approx::abs_diff_eq!(a, b, epsilon = 1e-8));
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1e-10));
+        assert!(approx::relative_eq!(a, b, max_relative = 1e-8));
+        b.frame = "base".into();
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1.0));
+    }
+}
@@ -0,0 +1,155 @@
+//! Bare positions: a point in space without an orientation.
+
+use crate::{
+    Localized, Transform, Transformable,
+    errors::TransformError,
+    geometry::Vector3,
+    time::{TimePoint, Timestamp},
+};
+
+use alloc::string::String;
+use approx::{AbsDiffEq, RelativeEq};
+
+/// A position in a frame at a moment in time, without an orientation.
+///
+/// For data that only has positions (detections, waypoints, cloud points)
+/// this avoids filling in the identity orientation a [`Point`] would need,
+/// while keeping the frame and timestamp checks of [`Transformable`]. To map
+/// vectors with no frame or timestamp at all, use
+/// [`Transform::transform_position`] or [`Transform::transform_positions`].
+///
+/// [`Point`]: crate::geometry::Point
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Transform, Transformable,
+///     geometry::{Position, Quaternion, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let mut detection = Position {
+///     position: Vector3::new(1.0, 2.0, 3.0),
+///     timestamp: Timestamp::zero(),
+///     frame: "camera".into(),
+/// };
+///
+/// let mount = Transform {
+///     translation: Vector3::new(0.5, 0.0, 0.0),
+///     rotation: Quaternion::identity(),
+///     timestamp: Timestamp::zero(),
+///     parent: "base".into(),
+///     child: "camera".into(),
+/// };
+///
+/// detection.transform(&mount).unwrap();
+/// assert_eq!(detection.frame, "base");
+/// assert_eq!(detection.position, Vector3::new(1.5, 2.0, 3.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The 3D position.
+    pub position: Vector3,
+    /// The time the position was observed at.
+    pub timestamp: T,
+    /// The reference frame of the position.
+    pub frame: String,
+}
+
+impl<T> Transformable<T> for Position<T>
+where
+    T: TimePoint,
+{
+    /// Applies a transformation to the position, updating it and its frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransformError`] if the position's frame does not match
+    /// the transform's child frame, or if the timestamps do not match.
+    /// Static transforms (carrying the static timestamp value) are valid for
+    /// all time and apply to a position of any timestamp.
+    fn transform(
+        &mut self,
+        transform: &Transform<T>,
+    ) -> Result<(), TransformError> {
+        if self.frame != transform.child {
+            return Err(TransformError::IncompatibleFrames);
+        }
+        if self.timestamp != transform.timestamp && !transform.timestamp.is_static() {
+            return Err(TransformError::TimestampMismatch(
+                self.timestamp.as_seconds_lossy(),
+                transform.timestamp.as_seconds_lossy(),
+            ));
+        }
+        self.position = transform.transform_position(self.position);
+        self.frame.clone_from(&transform.parent);
+        Ok(())
+    }
+}
+
+impl<T> Localized<T> for Position<T>
+where
+    T: TimePoint,
+{
+    fn frame(&self) -> &str {
+        &self.frame
+    }
+
+    fn timestamp(&self) -> T {
+        self.timestamp
+    }
+}
+
+impl<T> AbsDiffEq for Position<T>
+where
+    T: TimePoint,
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares the coordinates within `epsilon`; the frame and timestamp
+    /// must be identical.
+    fn abs_diff_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+    ) -> bool {
+        self.position.abs_diff_eq(&other.position, epsilon)
+            && self.timestamp == other.timestamp
+            && self.frame == other.frame
+    }
+}
+
+impl<T> RelativeEq for Position<T>
+where
+    T: TimePoint,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares the coordinates with a relative tolerance; the frame and
+    /// timestamp must be identical.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.position
+            .relative_eq(&other.position, epsilon, max_relative)
+            && self.timestamp == other.timestamp
+            && self.frame == other.frame
+    }
+}
+
+#[cfg(test)]
+mod tests;
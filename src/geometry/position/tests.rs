@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod position_tests {
+    use crate::{
+        Localized, Transform, Transformable,
+        errors::TransformError,
+        geometry::{Position, Quaternion, Vector3},
+        time::Timestamp,
+    };
+    use approx::assert_abs_diff_eq;
+
+    fn mount(timestamp: Timestamp) -> Transform {
+        Transform {
+            translation: Vector3::new(1.0, 0.0, 0.0),
+            rotation: Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2),
+            timestamp,
+            parent: "base".into(),
+            child: "camera".into(),
+        }
+    }
+
+    #[test]
+    fn transform_rotates_and_translates() {
+        let mut position = Position {
+            position: Vector3::new(2.0, 0.0, 1.0),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "camera".into(),
+        };
+
+        position.transform(&mount(Timestamp::zero())).unwrap();
+
+        assert_abs_diff_eq!(
+            position.position,
+            Vector3::new(1.0, 2.0, 1.0),
+            epsilon = 1e-12
+        );
+        assert_eq!(position.frame(), "base");
+        assert_eq!(position.timestamp(), Timestamp::from_nanos(1_000));
+    }
+
+    #[test]
+    fn transform_rejects_mismatched_frames_and_timestamps() {
+        let mut position = Position {
+            position: Vector3::zero(),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "lidar".into(),
+        };
+        assert!(matches!(
+            position.transform(&mount(Timestamp::from_nanos(1_000))),
+            Err(TransformError::IncompatibleFrames)
+        ));
+
+        position.frame = "camera".into();
+        assert!(matches!(
+            position.transform(&mount(Timestamp::from_nanos(2_000))),
+            Err(TransformError::TimestampMismatch(_, _))
+        ));
+        assert_eq!(position.frame, "camera");
+    }
+
+    #[test]
+    fn approx_comparison_uses_the_given_tolerance() {
+        let a = Position {
+            position: Vector3::new(1.0, 2.0, 3.0),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "camera".into(),
+        };
+        let mut b = a.clone();
+        b.position.x += 1e-9;
+
+        assert!(approx::abs_diff_eq!(a, b, epsilon = 1e-8));
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1e-10));
+        assert!(approx::relative_eq!(a, b, max_relative = 1e-8));
+        b.frame = "lidar".into();
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1.0));
+    }
+}
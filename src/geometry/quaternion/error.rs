@@ -13,4 +13,47 @@ pub enum QuaternionError {
     /// The quaternion has non-finite (NaN or infinite) components.
     #[error("quaternion has non-finite components")]
     NonFinite,
+    /// A direction was given as a (near-)zero-length vector.
+    #[error("cannot take the direction of a zero-length vector")]
+    ZeroLengthVector,
+    /// The matrix is not orthonormal or is a reflection, so it does not
+    /// describe a rotation.
+    #[error("matrix is not a rotation matrix")]
+    NotARotationMatrix,
+    /// An averaging weight is negative or not finite, or the weights sum to
+    /// zero.
+    #[error("averaging weights must be finite, non-negative and not all zero")]
+    InvalidWeight,
+    /// The rotations are spread too widely to have a unique average.
+    #[error("rotations are spread too widely for a unique average")]
+    AmbiguousAverage,
+}
+
+impl QuaternionError {
+    /// Returns the stable numeric code of the error variant.
+    ///
+    /// Codes never change between releases and are never reused, so they
+    /// can be logged, stored, or matched on across crate versions. Wrapping
+    /// variants report their own code; inspect the wrapped error for its
+    /// cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::errors::QuaternionError;
+    ///
+    /// assert_eq!(QuaternionError::DivisionByZero.code(), 300);
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::DivisionByZero => 300,
+            Self::ZeroLengthNormalization => 301,
+            Self::NonFinite => 302,
+            Self::ZeroLengthVector => 303,
+            Self::NotARotationMatrix => 304,
+            Self::InvalidWeight => 305,
+            Self::AmbiguousAverage => 306,
+        }
+    }
 }
@@ -0,0 +1,158 @@
+use crate::geometry::{Quaternion, math};
+
+/// The axis sequence of a set of Euler angles.
+///
+/// Both orders rotate about the fixed axes of the parent frame, in the
+/// sequence of their name, and the angles are given in that same sequence.
+/// Rotating about fixed axes `x, y, z` is the same rotation as about the
+/// moving axes `z, y', x''` with the angles reversed, so every
+/// convention in common use maps onto one of these.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    /// Angles `[x, y, z]` about the fixed x, then y, then z axis:
+    /// `R = Rz(z) * Ry(y) * Rx(x)`.
+    ///
+    /// This is roll, pitch, yaw as used by ROS and most robot datasheets,
+    /// and the same as intrinsic yaw-pitch-roll (`ZYX`) in aerospace.
+    Xyz,
+    /// Angles `[z, y, x]` about the fixed z, then y, then x axis:
+    /// `R = Rx(x) * Ry(y) * Rz(z)`.
+    ///
+    /// The same as intrinsic `XYZ` (about x, then the new y, then the new
+    /// z) with the angles reversed.
+    Zyx,
+}
+
+impl Quaternion {
+    /// Creates a rotation from roll, pitch and yaw angles in radians.
+    ///
+    /// Rotates about the fixed x-axis by `roll`, then the fixed y-axis by
+    /// `pitch`, then the fixed z-axis by `yaw`; see [`EulerOrder::Xyz`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{Quaternion, Vector3};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let yaw_90 = Quaternion::from_euler(0.0, 0.0, core::f64::consts::FRAC_PI_2);
+    /// assert_abs_diff_eq!(
+    ///     yaw_90.rotate_vector(Vector3::unit_x()),
+    ///     Vector3::unit_y(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_euler(
+        roll: f64,
+        pitch: f64,
+        yaw: f64,
+    ) -> Self {
+        Self::from_euler_angles(EulerOrder::Xyz, [roll, pitch, yaw])
+    }
+
+    /// Creates a rotation from Euler angles in radians, given in the
+    /// sequence of `order`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{EulerOrder, Quaternion};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let rpy = Quaternion::from_euler(0.1, 0.2, 0.3);
+    /// let same = Quaternion::from_euler_angles(EulerOrder::Xyz, [0.1, 0.2, 0.3]);
+    /// assert_abs_diff_eq!(rpy, same);
+    /// ```
+    #[must_use]
+    pub fn from_euler_angles(
+        order: EulerOrder,
+        angles: [f64; 3],
+    ) -> Self {
+        let [first, second, third] = angles;
+        match order {
+            EulerOrder::Xyz => {
+                Self::from_rotation_z(third)
+                    * Self::from_rotation_y(second)
+                    * Self::from_rotation_x(first)
+            }
+            EulerOrder::Zyx => {
+                Self::from_rotation_x(third)
+                    * Self::from_rotation_y(second)
+                    * Self::from_rotation_z(first)
+            }
+        }
+    }
+
+    /// Returns the Euler angles of a unit quaternion in radians, in the
+    /// sequence of `order`.
+    ///
+    /// The middle angle lies in `[-π/2, π/2]`, the outer two in `[-π, π]`.
+    /// At the singularity where the middle angle is `±π/2` (gimbal lock)
+    /// only the sum or difference of the outer angles is defined; the
+    /// first angle is then reported as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{EulerOrder, Quaternion};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let q = Quaternion::from_euler(0.1, -0.2, 0.3);
+    /// let [roll, pitch, yaw] = q.to_euler(EulerOrder::Xyz);
+    /// assert_abs_diff_eq!(roll, 0.1, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(pitch, -0.2, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(yaw, 0.3, epsilon = 1e-12);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_euler(
+        self,
+        order: EulerOrder,
+    ) -> [f64; 3] {
+        // Below this cosine of the middle angle the outer axes coincide.
+        const GIMBAL_LOCK: f64 = 1e-9;
+
+        let [[r00, r01, r02], [r10, r11, r12], [r20, r21, r22]] = self.to_rotation_matrix();
+        match order {
+            EulerOrder::Xyz => {
+                let y = math::asin((-r20).clamp(-1.0, 1.0));
+                if math::sqrt(r00 * r00 + r10 * r10) < GIMBAL_LOCK {
+                    [0.0, y, math::atan2(-r01, r11)]
+                } else {
+                    [math::atan2(r21, r22), y, math::atan2(r10, r00)]
+                }
+            }
+            EulerOrder::Zyx => {
+                let y = math::asin(r02.clamp(-1.0, 1.0));
+                if math::sqrt(r00 * r00 + r01 * r01) < GIMBAL_LOCK {
+                    [0.0, y, math::atan2(r21, r11)]
+                } else {
+                    [math::atan2(-r01, r00), y, math::atan2(-r12, r22)]
+                }
+            }
+        }
+    }
+
+    /// Returns the heading of a unit quaternion: the angle in radians of its
+    /// rotated x-axis projected onto the xy-plane, in `[-π, π]`.
+    ///
+    /// This equals the yaw of [`EulerOrder::Xyz`] and is the rotation a
+    /// planar robot stack cares about. If the x-axis is rotated to vertical,
+    /// the heading is undefined and reported as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Quaternion;
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let q = Quaternion::from_euler(0.1, 0.2, -1.3);
+    /// assert_abs_diff_eq!(q.yaw(), -1.3, epsilon = 1e-12);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn yaw(self) -> f64 {
+        let [[r00, ..], [r10, ..], _] = self.to_rotation_matrix();
+        math::atan2(r10, r00)
+    }
+}
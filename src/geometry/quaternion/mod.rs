@@ -1,53 +1,13 @@
 //! Quaternions for representing rotations in 3D space.
 
-use crate::geometry::Vector3;
+use crate::geometry::{Vector3, math};
 use approx::{AbsDiffEq, RelativeEq};
 use core::ops::{Add, Div, Mul, Sub};
 pub use error::QuaternionError;
+pub use euler::EulerOrder;
 
 mod error;
-
-/// Float math that works with and without `std`.
-///
-/// `f64::sqrt`, `sin`, and `acos` are `std` methods rather than `core`
-/// intrinsics; without `std` the equivalent `libm` implementations are used.
-mod math {
-    #[inline]
-    pub fn sqrt(x: f64) -> f64 {
-        #[cfg(feature = "std")]
-        {
-            x.sqrt()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            libm::sqrt(x)
-        }
-    }
-
-    #[inline]
-    pub fn sin(x: f64) -> f64 {
-        #[cfg(feature = "std")]
-        {
-            x.sin()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            libm::sin(x)
-        }
-    }
-
-    #[inline]
-    pub fn acos(x: f64) -> f64 {
-        #[cfg(feature = "std")]
-        {
-            x.acos()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            libm::acos(x)
-        }
-    }
-}
+mod euler;
 
 /// A quaternion representing a rotation in 3D space.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -122,6 +82,69 @@ impl Quaternion {
         }
     }
 
+    /// Creates the rotation by `angle` radians about the x-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{Quaternion, Vector3};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let q = Quaternion::from_rotation_x(core::f64::consts::FRAC_PI_2);
+    /// assert_abs_diff_eq!(
+    ///     q.rotate_vector(Vector3::unit_y()),
+    ///     Vector3::unit_z(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let half = angle / 2.0;
+        Self::new(math::cos(half), math::sin(half), 0.0, 0.0)
+    }
+
+    /// Creates the rotation by `angle` radians about the y-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{Quaternion, Vector3};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let q = Quaternion::from_rotation_y(core::f64::consts::FRAC_PI_2);
+    /// assert_abs_diff_eq!(
+    ///     q.rotate_vector(Vector3::unit_z()),
+    ///     Vector3::unit_x(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let half = angle / 2.0;
+        Self::new(math::cos(half), 0.0, math::sin(half), 0.0)
+    }
+
+    /// Creates the rotation by `angle` radians about the z-axis (yaw).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{Quaternion, Vector3};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let q = Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2);
+    /// assert_abs_diff_eq!(
+    ///     q.rotate_vector(Vector3::unit_x()),
+    ///     Vector3::unit_y(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let half = angle / 2.0;
+        Self::new(math::cos(half), 0.0, 0.0, math::sin(half))
+    }
+
     /// Returns the conjugate of the quaternion.
     ///
     /// # Examples
@@ -277,6 +300,130 @@ impl Quaternion {
         }
     }
 
+    /// The accepted deviation from orthonormality in
+    /// [`Quaternion::from_rotation_matrix`].
+    ///
+    /// Every entry of `M * Mᵀ` may differ from the identity by this much,
+    /// which accepts calibration matrices stored as `f32` or printed with a
+    /// limited number of digits.
+    pub const ORTHONORMAL_TOLERANCE: f64 = 1e-6;
+
+    /// Converts a row-major rotation matrix into a unit quaternion.
+    ///
+    /// Of the two quaternions describing the rotation, the one with a
+    /// non-negative `w` is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::NonFinite` if any entry is NaN or infinite,
+    /// and `QuaternionError::NotARotationMatrix` if the matrix is not
+    /// orthonormal within [`Quaternion::ORTHONORMAL_TOLERANCE`] or is a
+    /// reflection (negative determinant).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{errors::QuaternionError, geometry::Quaternion};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// // A camera looking along the body x-axis: optical z forward,
+    /// // x right, y down.
+    /// let camera = [[0.0, 0.0, 1.0], [-1.0, 0.0, 0.0], [0.0, -1.0, 0.0]];
+    /// let q = Quaternion::from_rotation_matrix(camera).unwrap();
+    /// assert_abs_diff_eq!(q, Quaternion::new(0.5, -0.5, 0.5, -0.5), epsilon = 1e-12);
+    ///
+    /// let mirror = [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    /// assert!(matches!(
+    ///     Quaternion::from_rotation_matrix(mirror),
+    ///     Err(QuaternionError::NotARotationMatrix)
+    /// ));
+    /// ```
+    pub fn from_rotation_matrix(matrix: [[f64; 3]; 3]) -> Result<Quaternion, QuaternionError> {
+        if matrix.iter().flatten().any(|value| !value.is_finite()) {
+            return Err(QuaternionError::NonFinite);
+        }
+        let [row_x, row_y, row_z] = matrix.map(|[x, y, z]| Vector3::new(x, y, z));
+        let orthonormal = [
+            (row_x.dot(row_x), 1.0),
+            (row_y.dot(row_y), 1.0),
+            (row_z.dot(row_z), 1.0),
+            (row_x.dot(row_y), 0.0),
+            (row_x.dot(row_z), 0.0),
+            (row_y.dot(row_z), 0.0),
+        ]
+        .iter()
+        .all(|(value, expected)| (value - expected).abs() <= Self::ORTHONORMAL_TOLERANCE);
+        if !orthonormal || row_x.dot(row_y.cross(row_z)) <= 0.0 {
+            return Err(QuaternionError::NotARotationMatrix);
+        }
+
+        // Shepperd's method: divide by the largest of the four candidates
+        // for numerical stability.
+        let [[m00, m01, m02], [m10, m11, m12], [m20, m21, m22]] = matrix;
+        let trace = m00 + m11 + m22;
+        let q = if trace > 0.0 {
+            let s = 2.0 * math::sqrt(trace + 1.0);
+            Quaternion::new(0.25 * s, (m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s)
+        } else if m00 > m11 && m00 > m22 {
+            let s = 2.0 * math::sqrt(1.0 + m00 - m11 - m22);
+            Quaternion::new((m21 - m12) / s, 0.25 * s, (m01 + m10) / s, (m02 + m20) / s)
+        } else if m11 > m22 {
+            let s = 2.0 * math::sqrt(1.0 + m11 - m00 - m22);
+            Quaternion::new((m02 - m20) / s, (m01 + m10) / s, 0.25 * s, (m12 + m21) / s)
+        } else {
+            let s = 2.0 * math::sqrt(1.0 + m22 - m00 - m11);
+            Quaternion::new((m10 - m01) / s, (m02 + m20) / s, (m12 + m21) / s, 0.25 * s)
+        };
+        if q.w < 0.0 {
+            q.scale(-1.0).normalize()
+        } else {
+            q.normalize()
+        }
+    }
+
+    /// Returns the rotation matrix of a unit quaternion, row-major.
+    ///
+    /// `matrix * v` rotates `v` exactly like [`Quaternion::rotate_vector`].
+    /// The quaternion is assumed to be normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Quaternion;
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let yaw_90 = Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2);
+    ///
+    /// let matrix = yaw_90.to_rotation_matrix();
+    /// let expected = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+    /// for (row, expected_row) in matrix.iter().zip(&expected) {
+    ///     for (value, expected_value) in row.iter().zip(expected_row) {
+    ///         assert_abs_diff_eq!(value, expected_value, epsilon = 1e-12);
+    ///     }
+    /// }
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_rotation_matrix(self) -> [[f64; 3]; 3] {
+        let Self { w, x, y, z } = self;
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]
+    }
+
     /// Performs spherical linear interpolation (slerp) between two quaternions.
     ///
     /// Interpolates between `self` and `other` by the factor `t`, which is
@@ -336,6 +483,234 @@ impl Quaternion {
 
         self.scale(scale_self) + other.scale(scale_other)
     }
+
+    /// Returns the rotation vector (axis scaled by angle) of a unit
+    /// quaternion, along the shortest arc: the angle lies in `[0, π]`.
+    pub(crate) fn to_rotation_vector(self) -> Vector3 {
+        let q = if self.w < 0.0 { self.scale(-1.0) } else { self };
+        let axis = Vector3::new(q.x, q.y, q.z);
+        let sin_half = axis.norm();
+        // Below this, angle / sin(angle / 2) is 2 to within f64 precision.
+        if sin_half < f64::EPSILON {
+            axis * 2.0
+        } else {
+            axis * (2.0 * math::atan2(sin_half, q.w) / sin_half)
+        }
+    }
+
+    /// Returns the rotation by `|v|` radians about the direction of `v`.
+    pub(crate) fn from_rotation_vector(v: Vector3) -> Quaternion {
+        let angle = v.norm();
+        if angle < f64::EPSILON {
+            // sin(angle / 2) / angle is 1/2 to within f64 precision.
+            return Quaternion::new(1.0, v.x / 2.0, v.y / 2.0, v.z / 2.0);
+        }
+        let half = angle / 2.0;
+        let axis = v * (math::sin(half) / angle);
+        Quaternion::new(math::cos(half), axis.x, axis.y, axis.z)
+    }
+
+    /// Returns the angle in radians of the rotation from `self` to `other`.
+    ///
+    /// This is the geodesic distance between two unit quaternions, in
+    /// `[0, π]`. `q` and `-q` describe the same rotation and are zero apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Quaternion;
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let a = Quaternion::from_rotation_z(0.2);
+    /// let b = Quaternion::from_rotation_z(0.5);
+    /// assert_abs_diff_eq!(a.angle_to(b), 0.3, epsilon = 1e-12);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn angle_to(
+        self,
+        other: Quaternion,
+    ) -> f64 {
+        let delta = self.conjugate() * other;
+        let sin_half = math::sqrt(delta.x * delta.x + delta.y * delta.y + delta.z * delta.z);
+        2.0 * math::atan2(sin_half, delta.w.abs())
+    }
+
+    /// Returns the shortest rotation that turns the direction of `from` into
+    /// the direction of `to`.
+    ///
+    /// The vectors need not be normalized. For opposite vectors the
+    /// rotation is by π about an arbitrary axis perpendicular to `from`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::ZeroLengthVector` if either vector has
+    /// (near-)zero length, and `QuaternionError::NonFinite` if either has a
+    /// NaN or infinite component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::{Quaternion, Vector3};
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// // Align a sensor's z-axis with a measured gravity direction.
+    /// let gravity = Vector3::new(0.0, -9.81, 0.0);
+    /// let q = Quaternion::rotation_between(Vector3::new(0.0, 0.0, -1.0), gravity).unwrap();
+    /// assert_abs_diff_eq!(
+    ///     q.rotate_vector(Vector3::unit_z()),
+    ///     Vector3::unit_y(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    pub fn rotation_between(
+        from: Vector3,
+        to: Vector3,
+    ) -> Result<Quaternion, QuaternionError> {
+        let unit = |v: Vector3| {
+            if !v.norm().is_finite() {
+                return Err(QuaternionError::NonFinite);
+            }
+            v.normalize().ok_or(QuaternionError::ZeroLengthVector)
+        };
+        let (from, to) = (unit(from)?, unit(to)?);
+
+        let cos = from.dot(to);
+        // Close to opposite, the cross product no longer defines an axis.
+        if cos < -1.0 + 1e-12 {
+            let mut axis = from.cross(Vector3::unit_x());
+            if axis.norm_squared() < 1e-12 {
+                axis = from.cross(Vector3::unit_y());
+            }
+            let axis = axis / axis.norm();
+            return Ok(Quaternion::new(0.0, axis.x, axis.y, axis.z));
+        }
+        let axis = from.cross(to);
+        Quaternion::new(1.0 + cos, axis.x, axis.y, axis.z).normalize()
+    }
+
+    /// The share of the total weight by which [`Quaternion::weighted_average`]
+    /// requires its result to exceed one half.
+    ///
+    /// Below it, the average is close to ambiguous: a small change in the
+    /// samples could turn it by up to 180°.
+    pub const AVERAGE_MARGIN: f64 = 1e-3;
+
+    /// Returns the weighted average of rotations, each paired with a
+    /// non-negative weight such as the confidence of an estimate.
+    ///
+    /// The average is the unit quaternion `q` maximizing `Σ wᵢ (qᵢ · q)²`,
+    /// the rotation closest to all samples in the chordal sense (Markley et
+    /// al., 2007): the dominant eigenvector of the samples' weighted scatter
+    /// matrix, found by power iteration. `q` and `-q` count as the same
+    /// rotation, samples need not be normalized, and the result has a
+    /// non-negative `w`.
+    ///
+    /// For two samples the average lies on the arc between them; for more it
+    /// weighs angles slightly differently from a weighted mean of angles.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::InvalidWeight` if a weight is negative or
+    /// not finite, or if the weights sum to zero or overflow.
+    ///
+    /// Returns `QuaternionError::NonFinite` or
+    /// `QuaternionError::ZeroLengthNormalization` if a sample cannot be
+    /// normalized.
+    ///
+    /// Returns `QuaternionError::AmbiguousAverage` if the samples are spread
+    /// too widely for a unique average: `Σ wᵢ (qᵢ · q)²` must exceed half
+    /// the total weight by [`Quaternion::AVERAGE_MARGIN`]. Equal weights on
+    /// two opposite half-turns, for example, have no single average.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Quaternion;
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let gyro = Quaternion::from_rotation_z(0.10);
+    /// let compass = Quaternion::from_rotation_z(0.20);
+    ///
+    /// let fused = Quaternion::weighted_average(&[(gyro, 1.0), (compass, 1.0)]).unwrap();
+    /// assert_abs_diff_eq!(fused, Quaternion::from_rotation_z(0.15), epsilon = 1e-12);
+    /// ```
+    pub fn weighted_average(samples: &[(Quaternion, f64)]) -> Result<Quaternion, QuaternionError> {
+        const MAX_ITERATIONS: usize = 10_000;
+        const TOLERANCE: f64 = 1e-14;
+
+        let mut total = 0.0;
+        for &(sample, weight) in samples {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(QuaternionError::InvalidWeight);
+            }
+            sample.normalize()?;
+            total += weight;
+        }
+        if !total.is_finite() || total <= 0.0 {
+            return Err(QuaternionError::InvalidWeight);
+        }
+
+        // The columns of the scatter matrix Σ wᵢ qᵢ qᵢᵀ of the unit samples,
+        // scaled to unit trace; the samples were validated above.
+        let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+        let mut columns = [zero; 4];
+        for &(sample, weight) in samples {
+            let sample = sample.scale(1.0 / sample.norm());
+            let components = [sample.w, sample.x, sample.y, sample.z];
+            for (column, component) in columns.iter_mut().zip(components) {
+                *column = *column + sample.scale(weight / total * component);
+            }
+        }
+        let [column_w, column_x, column_y, column_z] = columns;
+        let scatter = |q: Quaternion| {
+            column_w.scale(q.w) + column_x.scale(q.x) + column_y.scale(q.y) + column_z.scale(q.z)
+        };
+
+        // The eigenvalue of a unit eigenvector q is its support
+        // Σ wᵢ (qᵢ · q)² as a share of the total weight. The eigenvalues sum
+        // to one, so at most one can exceed a half. Its eigenvector v has a
+        // component λ vᵢ along each column i, and |vᵢ| ≥ 1/2 for at least
+        // one of them, so power iteration started from every column reaches
+        // it even when a start is orthogonal to it.
+        let mut best: Option<(f64, Quaternion)> = None;
+        for start in columns {
+            let Ok(mut estimate) = start.normalize() else {
+                continue;
+            };
+            for _ in 0..MAX_ITERATIONS {
+                let Ok(next) = scatter(estimate).normalize() else {
+                    break;
+                };
+                let converged = (next - estimate).norm() <= TOLERANCE;
+                estimate = next;
+                if converged {
+                    let support = estimate.dot(scatter(estimate));
+                    if best.is_none_or(|(most, _)| support > most) {
+                        best = Some((support, estimate));
+                    }
+                    break;
+                }
+            }
+        }
+        match best {
+            Some((support, estimate)) if support > 0.5 + Self::AVERAGE_MARGIN => {
+                Ok(if estimate.w < 0.0 {
+                    estimate.scale(-1.0)
+                } else {
+                    estimate
+                })
+            }
+            _ => Err(QuaternionError::AmbiguousAverage),
+        }
+    }
+
+    /// Returns the four-dimensional dot product of two quaternions.
+    fn dot(
+        self,
+        other: Quaternion,
+    ) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
 }
 
 impl Add for Quaternion {
@@ -2,7 +2,7 @@
 mod quaternion_tests {
     use crate::{
         errors::QuaternionError,
-        geometry::{Quaternion, Vector3},
+        geometry::{EulerOrder, Quaternion, Vector3},
     };
     use approx::{assert_abs_diff_eq, assert_relative_eq};
     use core::f64;
@@ -258,10 +258,304 @@ mod quaternion_tests {
     fn slerp_clamps_t_to_the_unit_interval() {
         let theta = core::f64::consts::PI / 2.0;
         let q1 = Quaternion::identity();
-        let q2 = Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin());
+        let q2 = Quaternion::from_rotation_z(theta);
 
         // No extrapolation: out-of-range factors saturate at the endpoints.
         assert_abs_diff_eq!(q1.slerp(q2, 2.0), q1.slerp(q2, 1.0));
         assert_abs_diff_eq!(q1.slerp(q2, -0.5), q1.slerp(q2, 0.0));
     }
+
+    #[test]
+    fn rotation_matrix_roundtrip() {
+        // One rotation per branch of the conversion: positive trace, and
+        // the x, y and z diagonal entry dominating.
+        for q in [
+            Quaternion::new(0.9, 0.1, -0.3, 0.2),
+            Quaternion::new(0.1, 0.9, 0.3, -0.2),
+            Quaternion::new(0.1, -0.3, 0.9, 0.2),
+            Quaternion::new(0.1, 0.2, -0.3, 0.9),
+        ] {
+            let q = q.normalize().unwrap();
+            let matrix = q.to_rotation_matrix();
+
+            for v in [Vector3::unit_x(), Vector3::new(0.3, -2.0, 1.5)] {
+                assert_abs_diff_eq!(
+                    v.rotate_by_matrix(&matrix),
+                    q.rotate_vector(v),
+                    epsilon = 1e-12
+                );
+            }
+            assert_abs_diff_eq!(
+                Quaternion::from_rotation_matrix(matrix).unwrap(),
+                q,
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn from_rotation_matrix_prefers_non_negative_w() {
+        let q = Quaternion::new(-0.5, 0.5, 0.5, 0.5);
+        let converted = Quaternion::from_rotation_matrix(q.to_rotation_matrix()).unwrap();
+        assert_abs_diff_eq!(converted, q.scale(-1.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn from_rotation_matrix_rejects_non_rotations() {
+        let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let scaled = [[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 2.0]];
+        let sheared = [[1.0, 0.1, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let reflection = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+        let mut nan = identity;
+        nan[1][2] = f64::NAN;
+
+        for matrix in [scaled, sheared, reflection] {
+            assert!(matches!(
+                Quaternion::from_rotation_matrix(matrix),
+                Err(QuaternionError::NotARotationMatrix)
+            ));
+        }
+        assert!(matches!(
+            Quaternion::from_rotation_matrix(nan),
+            Err(QuaternionError::NonFinite)
+        ));
+
+        // Rounding noise of f32 magnitude stays within the tolerance.
+        let q = Quaternion::new(0.9, 0.1, -0.3, 0.2).normalize().unwrap();
+        let noisy = q
+            .to_rotation_matrix()
+            .map(|row| row.map(|value| value + 1e-8));
+        assert_abs_diff_eq!(
+            Quaternion::from_rotation_matrix(noisy).unwrap(),
+            q,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            Quaternion::from_rotation_matrix(identity).unwrap(),
+            Quaternion::identity()
+        );
+    }
+
+    #[test]
+    fn from_euler_rotates_about_fixed_axes_in_order() {
+        let quarter = f64::consts::FRAC_PI_2;
+        // Roll by 90 degrees maps y to z, then yaw by 90 degrees maps the
+        // fixed x to y and z stays: y ends up at z.
+        let rpy = Quaternion::from_euler(quarter, 0.0, quarter);
+        assert_abs_diff_eq!(
+            rpy.rotate_vector(Vector3::unit_y()),
+            Vector3::unit_z(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            rpy.rotate_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            epsilon = 1e-12
+        );
+
+        // The same angles about z first, then x: y goes to -x, then stays.
+        let zyx = Quaternion::from_euler_angles(EulerOrder::Zyx, [quarter, 0.0, quarter]);
+        assert_abs_diff_eq!(
+            zyx.rotate_vector(Vector3::unit_y()),
+            Vector3::new(-1.0, 0.0, 0.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn euler_roundtrip() {
+        let angles = [[0.1, -0.2, 0.3], [-2.5, 1.2, 3.0], [0.0, -1.5, -0.7]];
+        for order in [EulerOrder::Xyz, EulerOrder::Zyx] {
+            for expected in angles {
+                let q = Quaternion::from_euler_angles(order, expected);
+                let actual = q.to_euler(order);
+                for (a, e) in actual.iter().zip(expected) {
+                    assert_abs_diff_eq!(*a, e, epsilon = 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_euler_at_gimbal_lock_reports_an_equivalent_rotation() {
+        let quarter = f64::consts::FRAC_PI_2;
+        for order in [EulerOrder::Xyz, EulerOrder::Zyx] {
+            let q = Quaternion::from_euler_angles(order, [0.4, quarter, 0.3]);
+            let angles = q.to_euler(order);
+
+            assert_abs_diff_eq!(angles[0], 0.0);
+            assert_abs_diff_eq!(angles[1], quarter, epsilon = 1e-6);
+            let back = Quaternion::from_euler_angles(order, angles);
+            for v in [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()] {
+                assert_abs_diff_eq!(back.rotate_vector(v), q.rotate_vector(v), epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn single_axis_rotations_match_axis_angle() {
+        let angle: f64 = 0.7;
+        let (sin, cos) = ((angle / 2.0).sin(), (angle / 2.0).cos());
+
+        assert_eq!(
+            Quaternion::from_rotation_x(angle),
+            Quaternion::new(cos, sin, 0.0, 0.0)
+        );
+        assert_eq!(
+            Quaternion::from_rotation_y(angle),
+            Quaternion::new(cos, 0.0, sin, 0.0)
+        );
+        assert_eq!(
+            Quaternion::from_rotation_z(angle),
+            Quaternion::new(cos, 0.0, 0.0, sin)
+        );
+        assert_eq!(
+            Quaternion::from_euler(angle, 0.0, 0.0),
+            Quaternion::from_rotation_x(angle)
+        );
+    }
+
+    #[test]
+    fn angle_to_is_the_geodesic_distance() {
+        let a = Quaternion::from_euler(0.1, 0.2, 0.3);
+        let b = a * Quaternion::from_rotation_y(1.1);
+
+        assert_abs_diff_eq!(a.angle_to(b), 1.1, epsilon = 1e-12);
+        assert_abs_diff_eq!(b.angle_to(a), 1.1, epsilon = 1e-12);
+        assert_abs_diff_eq!(a.angle_to(a.scale(-1.0)), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            Quaternion::identity().angle_to(Quaternion::from_rotation_x(f64::consts::PI)),
+            f64::consts::PI,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn rotation_between_aligns_directions() {
+        let pairs = [
+            (Vector3::new(1.0, 2.0, 3.0), Vector3::new(-2.0, 0.5, 1.0)),
+            (Vector3::unit_x(), Vector3::unit_x() * 4.0),
+            (Vector3::unit_x(), Vector3::new(-2.0, 0.0, 0.0)),
+            (Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, -1.0, 0.0)),
+        ];
+        for (from, to) in pairs {
+            let q = Quaternion::rotation_between(from, to).unwrap();
+            let norm = |v: Vector3| v.dot(v).sqrt();
+
+            assert_abs_diff_eq!(q.norm(), 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(
+                q.rotate_vector(from) / norm(from),
+                to / norm(to),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn rotation_between_rejects_degenerate_vectors() {
+        assert!(matches!(
+            Quaternion::rotation_between(Vector3::zero(), Vector3::unit_x()),
+            Err(QuaternionError::ZeroLengthVector)
+        ));
+        assert!(matches!(
+            Quaternion::rotation_between(Vector3::unit_x(), Vector3::new(f64::NAN, 0.0, 0.0)),
+            Err(QuaternionError::NonFinite)
+        ));
+    }
+
+    #[test]
+    fn weighted_average_of_one_sample_is_the_sample() {
+        let q = Quaternion::new(0.9, 0.1, -0.3, 0.2);
+        let average = Quaternion::weighted_average(&[(q, 2.0)]).unwrap();
+        assert_abs_diff_eq!(average, q.normalize().unwrap(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn weighted_average_maximizes_the_weighted_alignment() {
+        // About one axis, Σ wᵢ cos²((θᵢ - θ) / 2) peaks where
+        // Σ wᵢ sin(θᵢ - θ) = 0, i.e. tan θ = sin 0.4 / (3 + cos 0.4).
+        let expected = Quaternion::from_rotation_z((0.4_f64.sin() / (3.0 + 0.4_f64.cos())).atan());
+        let samples = [
+            (Quaternion::identity(), 3.0),
+            (Quaternion::from_rotation_z(0.4), 1.0),
+        ];
+        assert_abs_diff_eq!(
+            Quaternion::weighted_average(&samples).unwrap(),
+            expected,
+            epsilon = 1e-12
+        );
+
+        // Flipping signs or adding weightless samples changes nothing.
+        let flipped = [
+            (Quaternion::identity().scale(-1.0), 3.0),
+            (Quaternion::from_rotation_z(0.4), 1.0),
+            (Quaternion::from_rotation_x(2.0), 0.0),
+        ];
+        assert_abs_diff_eq!(
+            Quaternion::weighted_average(&flipped).unwrap(),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn weighted_average_of_a_spread_cluster_has_non_negative_w() {
+        let center = Quaternion::from_euler(0.3, -0.2, 2.9);
+        let samples = [
+            (center * Quaternion::from_rotation_x(0.05), 1.0),
+            (center * Quaternion::from_rotation_x(-0.05), 1.0),
+            (center * Quaternion::from_rotation_y(0.05), 1.0),
+            (center * Quaternion::from_rotation_y(-0.05), 1.0),
+        ];
+        let average = Quaternion::weighted_average(&samples).unwrap();
+        assert!(average.w >= 0.0);
+        assert_abs_diff_eq!(average.angle_to(center), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn weighted_average_finds_a_majority_split_across_samples() {
+        // The heaviest single sample is orthogonal to the average.
+        let half_turn = Quaternion::from_rotation_x(f64::consts::PI);
+        let split = [
+            (Quaternion::identity(), 0.4),
+            (half_turn, 0.3),
+            (half_turn, 0.3),
+        ];
+        let merged = [(half_turn, 0.6), (Quaternion::identity(), 0.4)];
+        for samples in [&split[..], &merged[..]] {
+            let average = Quaternion::weighted_average(samples).unwrap();
+            assert_abs_diff_eq!(average.angle_to(half_turn), 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn weighted_average_rejects_invalid_input() {
+        let q = Quaternion::identity();
+        for samples in [
+            &[][..],
+            &[(q, 0.0)][..],
+            &[(q, -1.0)][..],
+            &[(q, f64::NAN)][..],
+            &[(q, f64::MAX), (q, f64::MAX)][..],
+        ] {
+            assert!(matches!(
+                Quaternion::weighted_average(samples),
+                Err(QuaternionError::InvalidWeight)
+            ));
+        }
+        assert!(matches!(
+            Quaternion::weighted_average(&[(Quaternion::new(0.0, 0.0, 0.0, 0.0), 1.0)]),
+            Err(QuaternionError::ZeroLengthNormalization)
+        ));
+
+        // Opposite half-turns, or a sample a half-turn away from a cluster
+        // that it outweighs, have no unique average.
+        let half_turn = Quaternion::from_rotation_z(f64::consts::PI);
+        for samples in [[(q, 1.0), (half_turn, 1.0)], [(q, 1.0), (half_turn, 1.001)]] {
+            assert!(matches!(
+                Quaternion::weighted_average(&samples),
+                Err(QuaternionError::AmbiguousAverage)
+            ));
+        }
+    }
 }
@@ -22,6 +22,11 @@ pub enum TransformError {
     #[error("transform timestamps do not match (lhs: {0}, rhs: {1})")]
     TimestampMismatch(f64, f64),
 
+    /// A velocity lookup was given a zero-length window, over which no
+    /// motion can be measured.
+    #[error("the velocity window must be longer than zero")]
+    ZeroVelocityWindow,
+
     /// The requested timestamp lies outside the covered time range (all
     /// values in seconds: requested, range start, range end). There is no
     /// extrapolation.
@@ -78,3 +83,38 @@ pub enum TransformError {
     #[error("quaternion error: {0}")]
     QuaternionError(#[from] QuaternionError),
 }
+
+impl TransformError {
+    /// Returns the stable numeric code of the error variant.
+    ///
+    /// Codes never change between releases and are never reused, so they
+    /// can be logged, stored, or matched on across crate versions. Wrapping
+    /// variants report their own code; inspect the wrapped error for its
+    /// cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::errors::TransformError;
+    ///
+    /// assert_eq!(TransformError::UnknownFrame("odom".into()).code(), 107);
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::NonUnitRotation(_) => 100,
+            Self::NonFiniteValues => 101,
+            Self::TimestampMismatch(..) => 102,
+            Self::ZeroVelocityWindow => 103,
+            Self::TimestampOutOfRange(..) => 104,
+            Self::SameFrameMultiplication => 105,
+            Self::IncompatibleFrames => 106,
+            Self::UnknownFrame(_) => 107,
+            Self::Disconnected(..) => 108,
+            Self::NotFoundAt { .. } => 109,
+            Self::TransformTreeEmpty => 110,
+            Self::TimestampError(_) => 111,
+            Self::QuaternionError(_) => 112,
+        }
+    }
+}
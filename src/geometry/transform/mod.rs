@@ -11,6 +11,7 @@ pub use error::TransformError;
 pub use traits::{Localized, Transformable};
 
 mod error;
+mod se3;
 mod traits;
 
 /// Represents a 3D transformation with translation, rotation, and timestamp.
@@ -246,6 +247,130 @@ where
         }
     }
 
+    /// Creates a pure translation between two frames, with identity
+    /// rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// // A static lidar mount 1.2 units above the base.
+    /// let mount = Transform::from_translation(
+    ///     Vector3::new(0.0, 0.0, 1.2),
+    ///     "base",
+    ///     "lidar",
+    ///     Timestamp::zero(),
+    /// );
+    ///
+    /// assert_eq!(mount.rotation, Quaternion::identity());
+    /// assert_eq!(mount.child, "lidar");
+    /// ```
+    #[must_use]
+    pub fn from_translation(
+        translation: Vector3,
+        parent: &str,
+        child: &str,
+        timestamp: T,
+    ) -> Self {
+        Transform {
+            translation,
+            rotation: Quaternion::identity(),
+            timestamp,
+            parent: parent.into(),
+            child: child.into(),
+        }
+    }
+
+    /// Creates a pure rotation between two frames, with zero translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let rotation = Quaternion::from_euler(0.0, 0.1, 0.0);
+    /// let tilt = Transform::from_rotation(rotation, "base", "imu", Timestamp::zero());
+    ///
+    /// assert_eq!(tilt.translation, Vector3::zero());
+    /// assert_eq!(tilt.rotation, rotation);
+    /// ```
+    #[must_use]
+    pub fn from_rotation(
+        rotation: Quaternion,
+        parent: &str,
+        child: &str,
+        timestamp: T,
+    ) -> Self {
+        Transform {
+            translation: Vector3::zero(),
+            rotation,
+            timestamp,
+            parent: parent.into(),
+            child: child.into(),
+        }
+    }
+
+    /// Creates a pure rotation by `angle` radians about the x-axis.
+    ///
+    /// See [`Transform::from_rotation_z`].
+    #[must_use]
+    pub fn from_rotation_x(
+        angle: f64,
+        parent: &str,
+        child: &str,
+        timestamp: T,
+    ) -> Self {
+        Self::from_rotation(Quaternion::from_rotation_x(angle), parent, child, timestamp)
+    }
+
+    /// Creates a pure rotation by `angle` radians about the y-axis.
+    ///
+    /// See [`Transform::from_rotation_z`].
+    #[must_use]
+    pub fn from_rotation_y(
+        angle: f64,
+        parent: &str,
+        child: &str,
+        timestamp: T,
+    ) -> Self {
+        Self::from_rotation(Quaternion::from_rotation_y(angle), parent, child, timestamp)
+    }
+
+    /// Creates a pure rotation by `angle` radians about the z-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let angle = core::f64::consts::FRAC_PI_2;
+    /// let transform = Transform::from_rotation_z(angle, "base", "lidar", Timestamp::zero());
+    ///
+    /// assert_eq!(transform.translation, Vector3::zero());
+    /// assert_eq!(transform.rotation, Quaternion::from_rotation_z(angle));
+    /// assert_eq!(transform.parent, "base");
+    /// assert_eq!(transform.child, "lidar");
+    /// ```
+    #[must_use]
+    pub fn from_rotation_z(
+        angle: f64,
+        parent: &str,
+        child: &str,
+        timestamp: T,
+    ) -> Self {
+        Self::from_rotation(Quaternion::from_rotation_z(angle), parent, child, timestamp)
+    }
+
     /// Computes the inverse of the transform.
     ///
     /// Returns a new `Transform` that is the inverse of the current transform.
@@ -298,6 +423,80 @@ where
             child: self.parent.clone(),
         })
     }
+
+    /// Maps a position from the child frame into the parent frame.
+    ///
+    /// A bare vector has no frame or timestamp to check, so the caller is
+    /// responsible for applying the right transform;
+    /// [`Position`](crate::geometry::Position) carries both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    ///
+    /// let transform = Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::identity(),
+    ///     timestamp: Timestamp::zero(),
+    ///     parent: "base".into(),
+    ///     child: "camera".into(),
+    /// };
+    ///
+    /// let position = transform.transform_position(Vector3::new(0.0, 2.0, 0.0));
+    /// assert_eq!(position, Vector3::new(1.0, 2.0, 0.0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn transform_position(
+        &self,
+        position: Vector3,
+    ) -> Vector3 {
+        self.rotation.rotate_vector(position) + self.translation
+    }
+
+    /// Maps positions from the child frame into the parent frame in place.
+    ///
+    /// The rotation matrix is computed once for the whole slice, which makes
+    /// this considerably faster than transforming each position on its own,
+    /// e.g. for lidar clouds. Bare positions carry neither a frame nor a
+    /// timestamp, so the caller is responsible for applying the right
+    /// transform. Slices of [`Point`](crate::geometry::Point)s, which are
+    /// checked, implement [`Transformable`] directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    /// # use approx::assert_relative_eq;
+    ///
+    /// let transform = Transform {
+    ///     translation: Vector3::new(1.0, 0.0, 0.0),
+    ///     rotation: Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2),
+    ///     timestamp: Timestamp::zero(),
+    ///     parent: "base".into(),
+    ///     child: "lidar".into(),
+    /// };
+    ///
+    /// let mut cloud = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0)];
+    /// transform.transform_positions(&mut cloud);
+    /// assert_relative_eq!(cloud[0], Vector3::new(1.0, 1.0, 0.0), epsilon = 1e-12);
+    /// assert_relative_eq!(cloud[1], Vector3::new(-1.0, 0.0, 0.0), epsilon = 1e-12);
+    /// ```
+    pub fn transform_positions(
+        &self,
+        positions: &mut [Vector3],
+    ) {
+        let matrix = self.rotation.to_rotation_matrix();
+        for position in positions {
+            *position = position.rotate_by_matrix(&matrix) + self.translation;
+        }
+    }
 }
 
 impl<T> Mul for Transform<T>
@@ -0,0 +1,178 @@
+use crate::{
+    geometry::{Quaternion, Transform, Vector3, math},
+    time::TimePoint,
+};
+
+impl<T> Transform<T>
+where
+    T: TimePoint,
+{
+    /// Applies the fraction `s` of the transform's screw motion.
+    ///
+    /// Every rigid transform is a rotation about some axis combined with a
+    /// translation along it. `powf` scales both the angle and the
+    /// translation along that screw by `s`: `powf(0.0)` is the identity,
+    /// `powf(1.0)` the transform itself, and applying `powf(0.5)` twice
+    /// gives the whole transform. Unlike interpolating translation and
+    /// rotation separately, intermediate frames follow the arc a rigid body
+    /// would take. Frames and timestamp are kept.
+    ///
+    /// The rotation is taken along its shortest arc, so rotations by more
+    /// than half a turn are scaled from their shorter equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// // Half a turn about the z-axis through (1, 0, 0): the origin is
+    /// // carried to (2, 0, 0).
+    /// let door = Transform {
+    ///     translation: Vector3::new(2.0, 0.0, 0.0),
+    ///     rotation: Quaternion::from_rotation_z(core::f64::consts::PI),
+    ///     timestamp: Timestamp::zero(),
+    ///     parent: "frame".into(),
+    ///     child: "door".into(),
+    /// };
+    ///
+    /// // Halfway, the origin has swung around the hinge to (1, -1, 0)
+    /// // rather than sliding straight to (1, 0, 0).
+    /// let half_open = door.powf(0.5);
+    /// assert_abs_diff_eq!(
+    ///     half_open.translation,
+    ///     Vector3::new(1.0, -1.0, 0.0),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn powf(
+        &self,
+        s: f64,
+    ) -> Self {
+        Self::exp(
+            self.log().map(|coordinate| coordinate * s),
+            &self.parent,
+            &self.child,
+            self.timestamp,
+        )
+    }
+
+    /// Returns the SE(3) logarithm: the twist coordinates of the transform.
+    ///
+    /// The result is `[ρx, ρy, ρz, φx, φy, φz]`, translational part first.
+    /// `φ` is the rotation vector (axis times angle, the angle in `[0, π]`),
+    /// and `ρ` the translational part of the twist, which equals the
+    /// translation only for pure translations. [`Transform::exp`] is the
+    /// inverse, and the coordinates form the tangent space that pose-graph
+    /// and calibration solvers optimize in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// let transform = Transform::from_rotation(
+    ///     Quaternion::from_rotation_z(0.5),
+    ///     "map",
+    ///     "base",
+    ///     Timestamp::zero(),
+    /// );
+    ///
+    /// let xi = transform.log();
+    /// assert_abs_diff_eq!(xi[5], 0.5, epsilon = 1e-12);
+    ///
+    /// let back = Transform::exp(xi, "map", "base", Timestamp::zero());
+    /// assert_abs_diff_eq!(back, transform, epsilon = 1e-12);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn log(&self) -> [f64; 6] {
+        let phi = self.rotation.to_rotation_vector();
+        let theta = phi.norm();
+        // The inverse of V(φ) is I - [φ]×/2 + c * [φ]×².
+        let c = if theta < SMALL_ANGLE {
+            1.0 / 12.0 + theta * theta / 720.0
+        } else {
+            (1.0 - theta * math::sin(theta) / (2.0 * (1.0 - math::cos(theta)))) / (theta * theta)
+        };
+        let t = self.translation;
+        let phi_t = phi.cross(t);
+        let rho = t - phi_t / 2.0 + phi.cross(phi_t) * c;
+        [rho.x, rho.y, rho.z, phi.x, phi.y, phi.z]
+    }
+}
+
+impl<T> Transform<T>
+where
+    T: TimePoint,
+{
+    /// Builds a transform from twist coordinates, the SE(3) exponential.
+    ///
+    /// `xi` is `[ρx, ρy, ρz, φx, φy, φz]` as returned by [`Transform::log`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// // Without rotation, the twist is a plain translation.
+    /// let shift = Transform::exp([1.0, 2.0, 3.0, 0.0, 0.0, 0.0], "a", "b", Timestamp::zero());
+    /// assert_abs_diff_eq!(shift.translation, Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[must_use]
+    pub fn exp(
+        xi: [f64; 6],
+        parent: &str,
+        child: &str,
+        timestamp: T,
+    ) -> Self {
+        let [rho_x, rho_y, rho_z, phi_x, phi_y, phi_z] = xi;
+        let (translation, rotation) = exp_coordinates(
+            Vector3::new(rho_x, rho_y, rho_z),
+            Vector3::new(phi_x, phi_y, phi_z),
+        );
+        Transform {
+            translation,
+            rotation,
+            timestamp,
+            parent: parent.into(),
+            child: child.into(),
+        }
+    }
+}
+
+/// Below this angle, the trigonometric coefficients are evaluated by their
+/// Taylor series to avoid cancellation.
+const SMALL_ANGLE: f64 = 1e-4;
+
+/// Maps twist coordinates back to a translation and rotation.
+fn exp_coordinates(
+    rho: Vector3,
+    phi: Vector3,
+) -> (Vector3, Quaternion) {
+    let theta = phi.norm();
+    let theta_sq = theta * theta;
+    // V(φ) = I + a * [φ]× + b * [φ]×².
+    let (a, b) = if theta < SMALL_ANGLE {
+        (0.5 - theta_sq / 24.0, 1.0 / 6.0 - theta_sq / 120.0)
+    } else {
+        (
+            (1.0 - math::cos(theta)) / theta_sq,
+            (theta - math::sin(theta)) / (theta_sq * theta),
+        )
+    };
+    let phi_rho = phi.cross(rho);
+    let translation = rho + phi_rho * a + phi.cross(phi_rho) * b;
+    (translation, Quaternion::from_rotation_vector(phi))
+}
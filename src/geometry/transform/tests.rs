@@ -57,7 +57,7 @@ mod transform_tests {
 
         let t_a_b = Transform {
             translation: Vector3::zero(),
-            rotation: Quaternion::new((theta / 2.0).cos(), 0.0, 0.0, (theta / 2.0).sin()),
+            rotation: Quaternion::from_rotation_z(theta),
             timestamp: t,
             parent: "a".into(),
             child: "b".into(),
@@ -340,4 +340,174 @@ mod transform_tests {
             Err(TransformError::NonFiniteValues)
         ));
     }
+
+    #[test]
+    fn transform_positions_matches_per_vector_rotation() {
+        let rotation = Quaternion::new(0.9, 0.1, -0.3, 0.2).normalize().unwrap();
+        let transform = Transform {
+            translation: Vector3::new(1.0, -2.0, 0.5),
+            rotation,
+            timestamp: Timestamp::zero(),
+            parent: "base".into(),
+            child: "lidar".into(),
+        };
+        let original = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 2.0, -1.0),
+            Vector3::new(-3.0, 0.5, 4.0),
+        ];
+
+        let mut positions = original;
+        transform.transform_positions(&mut positions);
+
+        for (position, v) in positions.iter().zip(original) {
+            let expected = rotation.rotate_vector(v) + transform.translation;
+            approx::assert_abs_diff_eq!(*position, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn single_axis_rotation_constructors() {
+        let angle = 0.7;
+        let t = Timestamp::from_nanos(1_000);
+        for (transform, rotation) in [
+            (
+                Transform::from_rotation_x(angle, "a", "b", t),
+                Quaternion::from_rotation_x(angle),
+            ),
+            (
+                Transform::from_rotation_y(angle, "a", "b", t),
+                Quaternion::from_rotation_y(angle),
+            ),
+            (
+                Transform::from_rotation_z(angle, "a", "b", t),
+                Quaternion::from_rotation_z(angle),
+            ),
+        ] {
+            assert_eq!(
+                transform,
+                Transform {
+                    translation: Vector3::zero(),
+                    rotation,
+                    timestamp: t,
+                    parent: "a".into(),
+                    child: "b".into(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn translation_and_rotation_constructors_compose() {
+        let t = Timestamp::from_nanos(1_000);
+        let translation = Vector3::new(1.0, 2.0, 3.0);
+        let rotation = Quaternion::from_rotation_z(0.3);
+
+        let shift = Transform::from_translation(translation, "map", "odom", t);
+        let turn = Transform::from_rotation(rotation, "odom", "base", t);
+        assert_eq!(shift.rotation, Quaternion::identity());
+        assert_eq!(turn.translation, Vector3::zero());
+
+        assert_eq!(
+            (shift * turn).unwrap(),
+            Transform {
+                translation,
+                rotation,
+                timestamp: t,
+                parent: "map".into(),
+                child: "base".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn powf_scales_the_screw_motion() {
+        let t = Timestamp::from_nanos(1_000);
+        let transform = Transform {
+            translation: Vector3::new(1.0, -2.0, 0.5),
+            rotation: Quaternion::from_euler(0.3, -0.2, 1.1),
+            timestamp: t,
+            parent: "a".into(),
+            child: "b".into(),
+        };
+        let identity = Transform::from_translation(Vector3::zero(), "a", "b", t);
+
+        approx::assert_abs_diff_eq!(transform.powf(0.0), identity, epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(transform.powf(1.0), transform, epsilon = 1e-12);
+
+        // Two halves, or a third and two thirds, make up the whole.
+        let mut half = transform.powf(0.5);
+        half.child = "mid".into();
+        let mut rest = transform.powf(0.5);
+        rest.parent = "mid".into();
+        approx::assert_abs_diff_eq!((half * rest).unwrap(), transform, epsilon = 1e-12);
+
+        let mut third = transform.powf(1.0 / 3.0);
+        third.child = "mid".into();
+        let mut two_thirds = transform.powf(2.0 / 3.0);
+        two_thirds.parent = "mid".into();
+        approx::assert_abs_diff_eq!((third * two_thirds).unwrap(), transform, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn powf_of_pure_translation_is_proportional() {
+        let transform =
+            Transform::from_translation(Vector3::new(2.0, 4.0, -6.0), "a", "b", Timestamp::zero());
+
+        let quarter = transform.powf(0.25);
+        approx::assert_abs_diff_eq!(
+            quarter.translation,
+            Vector3::new(0.5, 1.0, -1.5),
+            epsilon = 1e-12
+        );
+        assert_eq!(quarter.rotation, Quaternion::identity());
+    }
+
+    #[test]
+    fn log_and_exp_are_inverse() {
+        let t = Timestamp::from_nanos(1_000);
+        for rotation in [
+            Quaternion::identity(),
+            Quaternion::from_rotation_x(1e-7),
+            Quaternion::from_euler(0.3, -0.2, 1.1),
+            Quaternion::from_rotation_y(3.0),
+        ] {
+            let transform = Transform {
+                translation: Vector3::new(1.0, -2.0, 0.5),
+                rotation,
+                timestamp: t,
+                parent: "a".into(),
+                child: "b".into(),
+            };
+            let back = Transform::exp(transform.log(), "a", "b", t);
+
+            approx::assert_abs_diff_eq!(back, transform, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn log_separates_rotation_vector_and_screw_translation() {
+        let angle = core::f64::consts::FRAC_PI_2;
+        // A quarter turn about the z-axis through (1, 0, 0).
+        let transform = Transform {
+            translation: Vector3::new(1.0, -1.0, 0.0),
+            rotation: Quaternion::from_rotation_z(angle),
+            timestamp: Timestamp::zero(),
+            parent: "a".into(),
+            child: "b".into(),
+        };
+
+        let [rho_x, rho_y, rho_z, phi_x, phi_y, phi_z] = transform.log();
+        approx::assert_abs_diff_eq!(
+            Vector3::new(phi_x, phi_y, phi_z),
+            Vector3::new(0.0, 0.0, angle),
+            epsilon = 1e-12
+        );
+        // For a pure rotation about an offset axis, ρ = -φ × hinge.
+        approx::assert_abs_diff_eq!(
+            Vector3::new(rho_x, rho_y, rho_z),
+            Vector3::new(0.0, -angle, 0.0),
+            epsilon = 1e-12
+        );
+    }
 }
@@ -0,0 +1,171 @@
+//! Planar (SE(2)) transforms for robots that live on the ground plane.
+
+use crate::{
+    geometry::{Quaternion, Transform, Vector3},
+    time::{TimePoint, Timestamp},
+};
+
+use alloc::string::String;
+use approx::{AbsDiffEq, RelativeEq};
+
+/// A rigid transform in the xy-plane: a translation `(x, y)` and a rotation
+/// by `yaw` radians about the z-axis.
+///
+/// Converts losslessly into a 3D [`Transform`] with `From`; the reverse,
+/// [`Transform::to_2d`], projects onto the plane.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     geometry::{Transform, Transform2d, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// let odom = Transform2d {
+///     x: 1.0,
+///     y: 2.0,
+///     yaw: core::f64::consts::FRAC_PI_2,
+///     timestamp: Timestamp::zero(),
+///     parent: "odom".into(),
+///     child: "base".into(),
+/// };
+///
+/// let transform = Transform::from(odom);
+/// assert_eq!(transform.translation, Vector3::new(1.0, 2.0, 0.0));
+/// assert_eq!(transform.child, "base");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2d<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The translation along the x-axis.
+    pub x: f64,
+    /// The translation along the y-axis.
+    pub y: f64,
+    /// The rotation about the z-axis, in radians.
+    pub yaw: f64,
+    /// The time at which the transform is valid.
+    pub timestamp: T,
+    /// The target frame; the transform maps child-frame coordinates into this frame.
+    pub parent: String,
+    /// The source frame whose coordinates are mapped into the parent frame.
+    pub child: String,
+}
+
+impl<T> From<Transform2d<T>> for Transform<T>
+where
+    T: TimePoint,
+{
+    fn from(transform: Transform2d<T>) -> Self {
+        Transform {
+            translation: Vector3::new(transform.x, transform.y, 0.0),
+            rotation: Quaternion::from_rotation_z(transform.yaw),
+            timestamp: transform.timestamp,
+            parent: transform.parent,
+            child: transform.child,
+        }
+    }
+}
+
+impl<T> Transform<T>
+where
+    T: TimePoint,
+{
+    /// Projects the transform onto the xy-plane.
+    ///
+    /// The z translation is dropped and the rotation reduced to its heading,
+    /// [`Quaternion::yaw`]; roll and pitch are discarded. Converting the
+    /// result back with `Transform::from` yields the original transform
+    /// only if it was planar to begin with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::{
+    ///     geometry::{Quaternion, Transform, Vector3},
+    ///     time::Timestamp,
+    /// };
+    /// # use approx::assert_abs_diff_eq;
+    ///
+    /// // A base on a slight slope, 0.1 units above the map plane.
+    /// let pose = Transform {
+    ///     translation: Vector3::new(3.0, 4.0, 0.1),
+    ///     rotation: Quaternion::from_euler(0.0, 0.05, 0.7),
+    ///     timestamp: Timestamp::zero(),
+    ///     parent: "map".into(),
+    ///     child: "base".into(),
+    /// };
+    ///
+    /// let planar = pose.to_2d();
+    /// assert_eq!((planar.x, planar.y), (3.0, 4.0));
+    /// assert_abs_diff_eq!(planar.yaw, 0.7, epsilon = 1e-12);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_2d(&self) -> Transform2d<T> {
+        Transform2d {
+            x: self.translation.x,
+            y: self.translation.y,
+            yaw: self.rotation.yaw(),
+            timestamp: self.timestamp,
+            parent: self.parent.clone(),
+            child: self.child.clone(),
+        }
+    }
+}
+
+impl<T> AbsDiffEq for Transform2d<T>
+where
+    T: TimePoint,
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares `x`, `y` and `yaw` within `epsilon`. Both frame names and the
+    /// timestamp have to match exactly.
+    fn abs_diff_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+    ) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.yaw.abs_diff_eq(&other.yaw, epsilon)
+            && self.timestamp == other.timestamp
+            && self.parent == other.parent
+            && self.child == other.child
+    }
+}
+
+impl<T> RelativeEq for Transform2d<T>
+where
+    T: TimePoint,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares `x`, `y` and `yaw` with a relative tolerance. Both frame names
+    /// and the timestamp have to match exactly.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.yaw.relative_eq(&other.yaw, epsilon, max_relative)
+            && self.timestamp == other.timestamp
+            && self.parent == other.parent
+            && self.child == other.child
+    }
+}
+
+#[cfg(test)]
+mod tests;
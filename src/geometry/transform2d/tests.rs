@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod transform2d_tests {
+    use crate::{
+        geometry::{Quaternion, Transform, Transform2d, Vector3},
+        time::Timestamp,
+    };
+    use approx::assert_abs_diff_eq;
+
+    fn planar(yaw: f64) -> Transform2d {
+        Transform2d {
+            x: 1.0,
+            y: -2.0,
+            yaw,
+            timestamp: Timestamp::from_nanos(1_000),
+            parent: "odom".into(),
+            child: "base".into(),
+        }
+    }
+
+    #[test]
+    fn planar_roundtrip() {
+        for yaw in [0.0, 0.7, -2.5, 3.1] {
+            let original = planar(yaw);
+            let lifted = Transform::from(original.clone());
+
+            assert_eq!(lifted.rotation, Quaternion::from_rotation_z(yaw));
+            let projected = lifted.to_2d();
+            assert_abs_diff_eq!(projected.yaw, yaw, epsilon = 1e-12);
+            assert_eq!((projected.x, projected.y), (original.x, original.y));
+            assert_eq!(projected.timestamp, original.timestamp);
+            assert_eq!(
+                (projected.parent, projected.child),
+                (original.parent, original.child)
+            );
+        }
+    }
+
+    #[test]
+    fn lifted_transform_maps_positions_in_the_plane() {
+        let lifted = Transform::from(planar(core::f64::consts::FRAC_PI_2));
+
+        assert_abs_diff_eq!(
+            lifted.transform_position(Vector3::new(1.0, 0.0, 0.5)),
+            Vector3::new(1.0, -1.0, 0.5),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn projection_drops_height_roll_and_pitch() {
+        let transform = Transform {
+            translation: Vector3::new(3.0, 4.0, 5.0),
+            rotation: Quaternion::from_euler(0.2, -0.3, 1.2),
+            timestamp: Timestamp::zero(),
+            parent: "map".into(),
+            child: "base".into(),
+        };
+
+        let projected = transform.to_2d();
+
+        assert_eq!((projected.x, projected.y), (3.0, 4.0));
+        assert_abs_diff_eq!(projected.yaw, 1.2, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn approx_comparison_uses_the_given_tolerance() {
+        let a = planar(0.7);
+        let mut b = a.clone();
+        b.yaw += 1e-9;
+
+        assert!(approx::abs_diff_eq!(a, b, epsilon = 1e-8));
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1e-10));
+        assert!(approx::relative_eq!(a, b, max_relative = 1e-8));
+        b.child = "lidar".into();
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1.0));
+    }
+}
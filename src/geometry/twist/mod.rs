@@ -0,0 +1,201 @@
+//! Rigid-body velocities: linear and angular velocity in a reference frame.
+
+use crate::{
+    Localized, Transform, Transformable,
+    errors::TransformError,
+    geometry::Vector3,
+    time::{TimePoint, Timestamp},
+};
+
+use alloc::string::String;
+use approx::{AbsDiffEq, RelativeEq};
+
+/// The velocity of a rigid body: a linear and an angular velocity, expressed
+/// in a reference frame at a moment in time.
+///
+/// `linear` is in units per second and `angular` in radians per second,
+/// as a rotation vector (axis scaled by rate). A twist is what
+/// [`Registry::lookup_velocity`](crate::core::Registry::lookup_velocity)
+/// returns.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     geometry::{Twist, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// // Driving forward at 1 m/s while turning left at 0.5 rad/s.
+/// let twist = Twist {
+///     linear: Vector3::new(1.0, 0.0, 0.0),
+///     angular: Vector3::new(0.0, 0.0, 0.5),
+///     timestamp: Timestamp::zero(),
+///     frame: "base".into(),
+/// };
+///
+/// assert_eq!(twist.angular.z, 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Twist<T = Timestamp>
+where
+    T: TimePoint,
+{
+    /// The linear velocity, in units per second.
+    pub linear: Vector3,
+    /// The angular velocity as a rotation vector, in radians per second.
+    pub angular: Vector3,
+    /// The time the velocity applies to.
+    pub timestamp: T,
+    /// The reference frame the velocity is expressed in.
+    pub frame: String,
+}
+
+/// Expresses a twist in the parent frame of a transform.
+///
+/// A twist describes the motion of a rigid body as the angular velocity and
+/// the linear velocity of the point at its frame's origin. Changing frames
+/// therefore applies the adjoint of the transform rather than just rotating
+/// both vectors: with rotation `R` and translation `p`,
+///
+/// ```text
+/// angular' = R * angular
+/// linear'  = R * linear + p × (R * angular)
+/// ```
+///
+/// so the linear velocity is that of the point at the parent frame's origin,
+/// which a rotating body sweeps along with the lever arm `p`.
+///
+/// # Examples
+///
+/// ```
+/// use transforms::{
+///     Transform, Transformable,
+///     geometry::{Quaternion, Twist, Vector3},
+///     time::Timestamp,
+/// };
+///
+/// // A sensor mounted one unit ahead of the base axle.
+/// let mount = Transform {
+///     translation: Vector3::new(1.0, 0.0, 0.0),
+///     rotation: Quaternion::identity(),
+///     timestamp: Timestamp::zero(),
+///     parent: "base".into(),
+///     child: "sensor".into(),
+/// };
+///
+/// // The sensor measures turning in place about its own origin.
+/// let mut twist = Twist {
+///     linear: Vector3::zero(),
+///     angular: Vector3::new(0.0, 0.0, 1.0),
+///     timestamp: Timestamp::zero(),
+///     frame: "sensor".into(),
+/// };
+///
+/// twist.transform(&mount).unwrap();
+/// assert_eq!(twist.frame, "base");
+/// // The base origin, behind the sensor, swings around it to the right.
+/// assert_eq!(twist.linear, Vector3::new(0.0, -1.0, 0.0));
+/// assert_eq!(twist.angular, Vector3::new(0.0, 0.0, 1.0));
+/// ```
+impl<T> Transformable<T> for Twist<T>
+where
+    T: TimePoint,
+{
+    /// Applies the adjoint of the transform, updating both velocities and
+    /// the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransformError`] if the twist's frame does not match the
+    /// transform's child frame, or if the timestamps do not match. Static
+    /// transforms (carrying the static timestamp value) are valid for all
+    /// time and apply to a twist of any timestamp.
+    fn transform(
+        &mut self,
+        transform: &Transform<T>,
+    ) -> Result<(), TransformError> {
+        if self.frame != transform.child {
+            return Err(TransformError::IncompatibleFrames);
+        }
+        if self.timestamp != transform.timestamp && !transform.timestamp.is_static() {
+            return Err(TransformError::TimestampMismatch(
+                self.timestamp.as_seconds_lossy(),
+                transform.timestamp.as_seconds_lossy(),
+            ));
+        }
+        let angular = transform.rotation.rotate_vector(self.angular);
+        self.linear =
+            transform.rotation.rotate_vector(self.linear) + transform.translation.cross(angular);
+        self.angular = angular;
+        self.frame.clone_from(&transform.parent);
+        Ok(())
+    }
+}
+
+impl<T> Localized<T> for Twist<T>
+where
+    T: TimePoint,
+{
+    fn frame(&self) -> &str {
+        &self.frame
+    }
+
+    fn timestamp(&self) -> T {
+        self.timestamp
+    }
+}
+
+impl<T> AbsDiffEq for Twist<T>
+where
+    T: TimePoint,
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares the linear and angular velocity within `epsilon`. Twists in
+    /// different frames or at different times are never equal.
+    fn abs_diff_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+    ) -> bool {
+        self.linear.abs_diff_eq(&other.linear, epsilon)
+            && self.angular.abs_diff_eq(&other.angular, epsilon)
+            && self.timestamp == other.timestamp
+            && self.frame == other.frame
+    }
+}
+
+impl<T> RelativeEq for Twist<T>
+where
+    T: TimePoint,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    /// Compares the linear and angular velocity with a relative tolerance.
+    /// Twists in different frames or at different times are never equal.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.linear
+            .relative_eq(&other.linear, epsilon, max_relative)
+            && self
+                .angular
+                .relative_eq(&other.angular, epsilon, max_relative)
+            && self.timestamp == other.timestamp
+            && self.frame == other.frame
+    }
+}
+
+#[cfg(test)]
+mod tests;
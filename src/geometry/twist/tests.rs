@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod twist_tests {
+    use crate::{
+        Localized, Transform, Transformable,
+        errors::TransformError,
+        geometry::{Quaternion, Twist, Vector3},
+        time::Timestamp,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn twist_is_localized() {
+        let twist = Twist {
+            linear: Vector3::new(1.0, 0.0, 0.0),
+            angular: Vector3::new(0.0, 0.0, 0.5),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "base".into(),
+        };
+
+        assert_eq!(twist.frame(), "base");
+        assert_eq!(twist.timestamp(), Timestamp::from_nanos(1_000));
+    }
+
+    #[test]
+    fn transform_applies_the_adjoint() {
+        // The sensor sits at (0, 2, 0) in base, yawed by 90 degrees.
+        let mount = Transform {
+            translation: Vector3::new(0.0, 2.0, 0.0),
+            rotation: Quaternion::from_rotation_z(core::f64::consts::FRAC_PI_2),
+            timestamp: Timestamp::zero(),
+            parent: "base".into(),
+            child: "sensor".into(),
+        };
+        let mut twist = Twist {
+            linear: Vector3::new(1.0, 0.0, 0.0),
+            angular: Vector3::new(0.0, 0.0, 0.5),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "sensor".into(),
+        };
+
+        twist.transform(&mount).unwrap();
+
+        // The sensor's forward motion points along base y; rotating about
+        // the sensor adds (0, 2, 0) × (0, 0, 0.5) = (1, 0, 0) at the base
+        // origin.
+        assert_abs_diff_eq!(twist.linear, Vector3::new(1.0, 1.0, 0.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(twist.angular, Vector3::new(0.0, 0.0, 0.5), epsilon = 1e-12);
+        assert_eq!(twist.frame, "base");
+        assert_eq!(twist.timestamp, Timestamp::from_nanos(1_000));
+    }
+
+    #[test]
+    fn transform_rejects_mismatched_frames_and_timestamps() {
+        let tf = Transform {
+            translation: Vector3::zero(),
+            rotation: Quaternion::identity(),
+            timestamp: Timestamp::from_nanos(2_000),
+            parent: "base".into(),
+            child: "sensor".into(),
+        };
+        let mut twist = Twist {
+            linear: Vector3::zero(),
+            angular: Vector3::zero(),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "camera".into(),
+        };
+
+        assert!(matches!(
+            twist.transform(&tf),
+            Err(TransformError::IncompatibleFrames)
+        ));
+        twist.frame = "sensor".into();
+        assert!(matches!(
+            twist.transform(&tf),
+            Err(TransformError::TimestampMismatch(_, _))
+        ));
+        assert_eq!(twist.frame, "sensor");
+    }
+
+    #[test]
+    fn approx_comparison_uses_the_given_tolerance() {
+        let a = Twist {
+            linear: Vector3::new(1.0, 0.0, 0.0),
+            angular: Vector3::new(0.0, 0.0, 0.5),
+            timestamp: Timestamp::from_nanos(1_000),
+            frame: "base".into(),
+        };
+        let mut b = a.clone();
+        b.angular.z += 1e-9;
+
+        assert!(approx::abs_diff_eq!(a, b, epsilon = 1e-8));
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1e-10));
+        assert!(approx::relative_eq!(a, b, max_relative = 1e-8));
+        b.timestamp = Timestamp::from_nanos(2_000);
+        assert!(approx::abs_diff_ne!(a, b, epsilon = 1.0));
+    }
+}
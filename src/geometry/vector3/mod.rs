@@ -1,6 +1,7 @@
-//! A 3D vector type with basic arithmetic, dot, and cross products.
+//! A 3D vector type with arithmetic, dot and cross products, and norms.
 
-use core::ops::{Add, Div, Mul, Sub};
+use crate::geometry::math;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use approx::{AbsDiffEq, RelativeEq};
 
@@ -118,6 +119,93 @@ impl Vector3 {
             z: self.x * other.y - self.y * other.x,
         }
     }
+
+    /// Computes the Euclidean length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(2.0, 3.0, 6.0).norm(), 7.0);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn norm(self) -> f64 {
+        math::sqrt(self.norm_squared())
+    }
+
+    /// Computes the squared Euclidean length, avoiding the square root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(2.0, 3.0, 6.0).norm_squared(), 49.0);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn norm_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the unit vector with the same direction.
+    ///
+    /// Returns `None` if the vector has (near-)zero length or a NaN or
+    /// infinite component, since it then has no direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Vector3;
+    ///
+    /// let v = Vector3::new(0.0, 3.0, 4.0);
+    /// assert_eq!(v.normalize(), Some(Vector3::new(0.0, 0.6, 0.8)));
+    /// assert_eq!(Vector3::zero().normalize(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn normalize(self) -> Option<Self> {
+        let norm = self.norm();
+        (norm.is_finite() && norm >= f64::EPSILON).then(|| self / norm)
+    }
+
+    /// Computes the Euclidean distance between two positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::geometry::Vector3;
+    ///
+    /// let a = Vector3::new(1.0, 1.0, 1.0);
+    /// let b = Vector3::new(4.0, 5.0, 1.0);
+    /// assert_eq!(a.distance(b), 5.0);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn distance(
+        self,
+        other: Self,
+    ) -> f64 {
+        (self - other).norm()
+    }
+
+    /// Multiplies a row-major 3x3 matrix with the vector.
+    #[must_use]
+    #[inline]
+    pub(crate) fn rotate_by_matrix(
+        self,
+        matrix: &[[f64; 3]; 3],
+    ) -> Self {
+        let [x_row, y_row, z_row] = *matrix;
+        let row_dot = |[a, b, c]: [f64; 3]| a * self.x + b * self.y + c * self.z;
+        Self {
+            x: row_dot(x_row),
+            y: row_dot(y_row),
+            z: row_dot(z_row),
+        }
+    }
 }
 
 impl Add for Vector3 {
@@ -203,6 +291,60 @@ impl Div<f64> for Vector3 {
     }
 }
 
+impl Neg for Vector3 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl AddAssign for Vector3 {
+    #[inline]
+    fn add_assign(
+        &mut self,
+        other: Self,
+    ) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vector3 {
+    #[inline]
+    fn sub_assign(
+        &mut self,
+        other: Self,
+    ) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<f64> for Vector3 {
+    #[inline]
+    fn mul_assign(
+        &mut self,
+        scalar: f64,
+    ) {
+        *self = *self * scalar;
+    }
+}
+
+impl DivAssign<f64> for Vector3 {
+    /// Scalar division with the same IEEE 754 semantics as [`Div`].
+    #[inline]
+    fn div_assign(
+        &mut self,
+        scalar: f64,
+    ) {
+        *self = *self / scalar;
+    }
+}
+
 impl AbsDiffEq for Vector3 {
     type Epsilon = f64;
 
@@ -51,4 +51,39 @@ mod vector3_tests {
         let expected = Vector3::new(-3.0, 6.0, -3.0);
         assert_eq!(v1.cross(v2), expected);
     }
+
+    #[test]
+    fn norms_and_distance() {
+        let v = Vector3::new(1.0, -2.0, 2.0);
+
+        assert_relative_eq!(v.norm_squared(), 9.0);
+        assert_relative_eq!(v.norm(), 3.0);
+        assert_relative_eq!(v.distance(Vector3::zero()), 3.0);
+        assert_relative_eq!(v.distance(v), 0.0);
+        assert_relative_eq!(v.normalize().unwrap(), v / 3.0);
+        assert_relative_eq!(v.normalize().unwrap().norm(), 1.0);
+    }
+
+    #[test]
+    fn normalize_rejects_vectors_without_direction() {
+        assert_eq!(Vector3::zero().normalize(), None);
+        assert_eq!(Vector3::new(1e-300, 0.0, 0.0).normalize(), None);
+        assert_eq!(Vector3::new(f64::NAN, 1.0, 0.0).normalize(), None);
+        assert_eq!(Vector3::new(f64::INFINITY, 1.0, 0.0).normalize(), None);
+    }
+
+    #[test]
+    fn negation_and_compound_assignment() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(-v, Vector3::new(-1.0, -2.0, -3.0));
+
+        v += Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(v, Vector3::new(2.0, 3.0, 4.0));
+        v -= Vector3::new(2.0, 2.0, 2.0);
+        assert_eq!(v, Vector3::new(0.0, 1.0, 2.0));
+        v *= 3.0;
+        assert_eq!(v, Vector3::new(0.0, 3.0, 6.0));
+        v /= 2.0;
+        assert_eq!(v, Vector3::new(0.0, 1.5, 3.0));
+    }
 }
@@ -216,4 +216,5 @@ pub mod errors;
 pub mod geometry;
 pub mod time;
 pub use core::Registry;
+pub use errors::{Error, Result};
 pub use geometry::{Localized, Transform, Transformable};
@@ -13,4 +13,33 @@ pub enum TimeError {
     /// Converting to seconds could not be done exactly.
     #[error("conversion to seconds lost accuracy")]
     AccuracyLoss,
+    /// A time in seconds was NaN or infinite.
+    #[error("seconds value is not finite")]
+    NonFinite,
+}
+
+impl TimeError {
+    /// Returns the stable numeric code of the error variant.
+    ///
+    /// Codes never change between releases and are never reused, so they
+    /// can be logged, stored, or matched on across crate versions. Wrapping
+    /// variants report their own code; inspect the wrapped error for its
+    /// cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::errors::TimeError;
+    ///
+    /// assert_eq!(TimeError::DurationUnderflow.code(), 400);
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::DurationUnderflow => 400,
+            Self::DurationOverflow => 401,
+            Self::AccuracyLoss => 402,
+            Self::NonFinite => 403,
+        }
+    }
 }
@@ -0,0 +1,289 @@
+//! Alternative serde representations of [`Timestamp`], for use with
+//! `#[serde(with = "...")]`.
+//!
+//! By default a `Timestamp` serializes as its struct, `{"t": nanos}`. The
+//! modules here change the representation of individual fields:
+//!
+//! - [`nanos`]: the bare nanosecond count, e.g. `1709210096789000000`.
+//! - [`rfc3339`]: a UTC date-time string, e.g.
+//!   `"2024-02-29T12:34:56.789Z"`, for configs and logs read by humans.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use transforms::time::Timestamp;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Calibration {
+//!     #[serde(with = "transforms::time::format::rfc3339")]
+//!     recorded: Timestamp,
+//!     #[serde(with = "transforms::time::format::nanos")]
+//!     valid_from: Timestamp,
+//! }
+//!
+//! let calibration = Calibration {
+//!     recorded: Timestamp::from_nanos(1_709_210_096_789_000_000),
+//!     valid_from: Timestamp::from_nanos(1_000),
+//! };
+//! let json = serde_json::to_string(&calibration).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"recorded":"2024-02-29T12:34:56.789Z","valid_from":1000}"#
+//! );
+//! ```
+
+use crate::time::Timestamp;
+
+/// Serializes a [`Timestamp`] as its bare nanosecond count.
+pub mod nanos {
+    use crate::time::Timestamp;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the nanosecond count as an unsigned integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error, e.g. if the format has no 128-bit
+    /// integers and the value does not fit a smaller one.
+    pub fn serialize<S>(
+        timestamp: &Timestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        timestamp.t.serialize(serializer)
+    }
+
+    /// Deserializes a nanosecond count.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer's error if the value is not a non-negative
+    /// integer.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u128::deserialize(deserializer).map(Timestamp::from_nanos)
+    }
+}
+
+/// Serializes a [`Timestamp`] as an RFC 3339 date-time string in UTC.
+///
+/// The timestamp is read as nanoseconds since the Unix epoch, the clock of
+/// [`Timestamp::now`]. Output always uses `Z` and includes as many
+/// fractional digits as needed, up to nine. Input may use any UTC offset,
+/// a lowercase `t`/`z` or a space as the separator, and at most nine
+/// fractional digits. Leap seconds (`:60`) and times before the epoch are
+/// rejected, since a `Timestamp` cannot represent them.
+pub mod rfc3339 {
+    use crate::time::Timestamp;
+    use alloc::string::String;
+    use serde::{Deserialize, Deserializer, Serializer, de, ser};
+
+    /// Serializes the timestamp as an RFC 3339 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serializer error if the timestamp lies after the year 9999,
+    /// which RFC 3339 cannot express.
+    pub fn serialize<S>(
+        timestamp: &Timestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let text = super::format(*timestamp).ok_or_else(|| {
+            <S::Error as ser::Error>::custom("timestamp lies after the year 9999")
+        })?;
+        serializer.serialize_str(&text)
+    }
+
+    /// Deserializes a timestamp from an RFC 3339 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a deserializer error if the value is not a valid RFC 3339
+    /// date-time, is a leap second, or lies before the Unix epoch.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        super::parse(&text).map_err(<D::Error as de::Error>::custom)
+    }
+}
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const SECONDS_PER_DAY: u128 = 86_400;
+/// Days from 1970-01-01 to 9999-12-31, the last date RFC 3339 can express.
+const LAST_DAY: u64 = 2_932_896;
+
+/// Formats a timestamp as `YYYY-MM-DDTHH:MM:SS[.fraction]Z`, or `None`
+/// after the year 9999.
+fn format(timestamp: Timestamp) -> Option<alloc::string::String> {
+    use core::fmt::Write;
+
+    let seconds = timestamp.t / NANOS_PER_SECOND;
+    let nanos = timestamp.t % NANOS_PER_SECOND;
+    let days = u64::try_from(seconds / SECONDS_PER_DAY)
+        .ok()
+        .filter(|&days| days <= LAST_DAY)?;
+    let second_of_day = seconds % SECONDS_PER_DAY;
+    let (year, month, day) = civil_from_days(days);
+
+    let mut text = alloc::string::String::new();
+    write!(
+        text,
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        second_of_day / 3600,
+        second_of_day % 3600 / 60,
+        second_of_day % 60,
+    )
+    .ok()?;
+    if nanos != 0 {
+        let fraction = alloc::format!("{nanos:09}");
+        write!(text, ".{}", fraction.trim_end_matches('0')).ok()?;
+    }
+    text.push('Z');
+    Some(text)
+}
+
+/// Parses an RFC 3339 date-time into nanoseconds since the Unix epoch.
+fn parse(text: &str) -> Result<Timestamp, &'static str> {
+    const INVALID: &str = "expected an RFC 3339 date-time such as 2024-02-29T12:34:56Z";
+
+    let bytes = text.as_bytes();
+    let number = |start: usize, len: usize| -> Result<u32, &'static str> {
+        let digits = bytes.get(start..start + len).ok_or(INVALID)?;
+        digits.iter().try_fold(0, |value: u32, &digit| {
+            if digit.is_ascii_digit() {
+                Ok(value * 10 + u32::from(digit - b'0'))
+            } else {
+                Err(INVALID)
+            }
+        })
+    };
+    let expect = |index: usize, allowed: &[u8]| match bytes.get(index) {
+        Some(byte) if allowed.contains(byte) => Ok(()),
+        _ => Err(INVALID),
+    };
+
+    let year = number(0, 4)?;
+    expect(4, b"-")?;
+    let month = number(5, 2)?;
+    expect(7, b"-")?;
+    let day = number(8, 2)?;
+    expect(10, b"Tt ")?;
+    let hour = number(11, 2)?;
+    expect(13, b":")?;
+    let minute = number(14, 2)?;
+    expect(16, b":")?;
+    let second = number(17, 2)?;
+
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err("date does not exist");
+    }
+    if hour > 23 || minute > 59 {
+        return Err("time of day out of range");
+    }
+    if second > 59 {
+        return Err("leap seconds cannot be represented");
+    }
+
+    let mut index = 19;
+    let mut nanos = 0;
+    if bytes.get(index) == Some(&b'.') {
+        index += 1;
+        let digits = bytes.get(index..).map_or(0, |rest| {
+            rest.iter().take_while(|b| b.is_ascii_digit()).count()
+        });
+        if digits == 0 {
+            return Err(INVALID);
+        }
+        if digits > 9 {
+            return Err("sub-nanosecond precision cannot be represented");
+        }
+        let fraction = number(index, digits)?;
+        nanos = u128::from(fraction) * 10_u128.pow(9 - u32::try_from(digits).map_err(|_| INVALID)?);
+        index += digits;
+    }
+
+    let offset_seconds: i128 = match bytes.get(index) {
+        Some(b'Z' | b'z') if bytes.len() == index + 1 => 0,
+        Some(&sign @ (b'+' | b'-')) if bytes.len() == index + 6 => {
+            let hours = number(index + 1, 2)?;
+            expect(index + 3, b":")?;
+            let minutes = number(index + 4, 2)?;
+            if hours > 23 || minutes > 59 {
+                return Err("UTC offset out of range");
+            }
+            let offset = i128::from(hours * 3600 + minutes * 60);
+            if sign == b'+' { offset } else { -offset }
+        }
+        _ => return Err(INVALID),
+    };
+
+    let local_seconds = i128::from(days_from_civil(year, month, day)) * 86_400
+        + i128::from(hour * 3600 + minute * 60 + second);
+    let seconds = u128::try_from(local_seconds - offset_seconds)
+        .map_err(|_| "timestamp lies before the Unix epoch")?;
+    Ok(Timestamp::from_nanos(seconds * NANOS_PER_SECOND + nanos))
+}
+
+fn is_leap_year(year: u32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(
+    year: u32,
+    month: u32,
+) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The two calendar conversions are Howard Hinnant's `days_from_civil` and
+// `civil_from_days` for the proleptic Gregorian calendar, counted in 400-year
+// eras of 146097 days.
+
+/// Days since 1970-01-01 of a date, negative before it.
+fn days_from_civil(
+    year: u32,
+    month: u32,
+    day: u32,
+) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date `days` after 1970-01-01, for `days` up to [`LAST_DAY`].
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
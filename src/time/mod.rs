@@ -10,6 +10,10 @@
 
 /// Error types for time operations.
 mod error;
+/// Alternative serde representations of [`Timestamp`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod format;
 /// The default [`Timestamp`] time type.
 pub mod timestamp;
 /// The [`TimePoint`] trait for custom time types.
@@ -90,6 +90,67 @@ impl Timestamp {
         Timestamp { t: nanos }
     }
 
+    /// Creates a `Timestamp` from a number of milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::time::Timestamp;
+    ///
+    /// let timestamp = Timestamp::from_millis(1_500);
+    /// assert_eq!(timestamp.as_nanos(), 1_500_000_000);
+    /// ```
+    #[must_use]
+    pub const fn from_millis(millis: u64) -> Self {
+        Timestamp {
+            t: millis as u128 * 1_000_000,
+        }
+    }
+
+    /// Creates a `Timestamp` from a number of seconds, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeError::NonFinite` if `seconds` is NaN or infinite,
+    /// `TimeError::DurationUnderflow` if it is negative, and
+    /// `TimeError::DurationOverflow` if it exceeds the `u128` nanosecond
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::time::Timestamp;
+    ///
+    /// let timestamp = Timestamp::from_seconds(1.25).unwrap();
+    /// assert_eq!(timestamp.as_nanos(), 1_250_000_000);
+    ///
+    /// assert!(Timestamp::from_seconds(-1.0).is_err());
+    /// assert!(Timestamp::from_seconds(f64::NAN).is_err());
+    /// ```
+    pub fn from_seconds(seconds: f64) -> Result<Self, TimeError> {
+        const NANOSECONDS_PER_SECOND: f64 = 1_000_000_000.0;
+        /// 2^128, the first nanosecond count a `u128` cannot hold.
+        const NANOS_LIMIT: f64 = 340_282_366_920_938_463_463_374_607_431_768_211_456.0;
+
+        if !seconds.is_finite() {
+            return Err(TimeError::NonFinite);
+        }
+        let nanos = (seconds * NANOSECONDS_PER_SECOND).round();
+        if nanos < 0.0 {
+            return Err(TimeError::DurationUnderflow);
+        }
+        if nanos >= NANOS_LIMIT {
+            return Err(TimeError::DurationOverflow);
+        }
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "nanos is a non-negative integer below 2^128"
+        )]
+        Ok(Timestamp { t: nanos as u128 })
+    }
+
     /// Returns the timestamp as nanoseconds.
     ///
     /// # Examples
@@ -105,6 +166,56 @@ impl Timestamp {
         self.t
     }
 
+    /// Returns the timestamp as whole milliseconds, truncating any remainder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::time::Timestamp;
+    ///
+    /// let timestamp = Timestamp::from_nanos(1_500_999_999);
+    /// assert_eq!(timestamp.as_millis(), 1_500);
+    /// ```
+    #[must_use]
+    pub const fn as_millis(&self) -> u128 {
+        self.t / 1_000_000
+    }
+
+    /// Returns the signed time from `earlier` to `self` in nanoseconds.
+    ///
+    /// Unlike `self - earlier`, which fails when `earlier` is the later
+    /// stamp, the result is negative in that case. This suits latency checks
+    /// such as "how late is this data", where either sign is expected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeError::DurationOverflow` if the difference does not fit
+    /// in an `i128`, which needs stamps more than 2^127 nanoseconds apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transforms::time::Timestamp;
+    ///
+    /// let stamped = Timestamp::from_nanos(5_000);
+    /// let received = Timestamp::from_nanos(3_000);
+    ///
+    /// assert_eq!(received.signed_duration_since(stamped).unwrap(), -2_000);
+    /// assert_eq!(stamped.signed_duration_since(received).unwrap(), 2_000);
+    /// ```
+    pub fn signed_duration_since(
+        self,
+        earlier: Timestamp,
+    ) -> Result<i128, TimeError> {
+        if self.t >= earlier.t {
+            i128::try_from(self.t - earlier.t).map_err(|_| TimeError::DurationOverflow)
+        } else {
+            i128::try_from(earlier.t - self.t)
+                .map(|difference| -difference)
+                .map_err(|_| TimeError::DurationOverflow)
+        }
+    }
+
     /// Converts the `Timestamp` to seconds as a floating-point number.
     ///
     /// `f64` has a 53-bit mantissa, so timestamps up to 2^53 nanoseconds
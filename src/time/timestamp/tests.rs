@@ -61,4 +61,75 @@ mod timestamp_tests {
         let big = Timestamp::from_nanos((1 << 53) + 1);
         assert!(big.as_seconds_unchecked().is_finite());
     }
+
+    #[test]
+    fn from_millis_and_as_millis() {
+        let timestamp = Timestamp::from_millis(1_234);
+        assert_eq!(timestamp, Timestamp::from_nanos(1_234_000_000));
+        assert_eq!(timestamp.as_millis(), 1_234);
+
+        assert_eq!(Timestamp::from_nanos(999_999).as_millis(), 0);
+        assert_eq!(
+            Timestamp::from_millis(u64::MAX).as_nanos(),
+            u128::from(u64::MAX) * 1_000_000
+        );
+    }
+
+    #[test]
+    fn from_seconds_rounds_to_the_nearest_nanosecond() {
+        assert_eq!(Timestamp::from_seconds(0.0).unwrap(), Timestamp::zero());
+        assert_eq!(
+            Timestamp::from_seconds(1.5).unwrap(),
+            Timestamp::from_nanos(1_500_000_000)
+        );
+        // 0.1 s is not exact in binary; rounding recovers the intended value.
+        assert_eq!(
+            Timestamp::from_seconds(0.1).unwrap(),
+            Timestamp::from_nanos(100_000_000)
+        );
+        assert_eq!(Timestamp::from_seconds(-0.0).unwrap(), Timestamp::zero());
+    }
+
+    #[test]
+    fn from_seconds_rejects_unrepresentable_values() {
+        assert!(matches!(
+            Timestamp::from_seconds(f64::NAN),
+            Err(TimeError::NonFinite)
+        ));
+        assert!(matches!(
+            Timestamp::from_seconds(f64::INFINITY),
+            Err(TimeError::NonFinite)
+        ));
+        assert!(matches!(
+            Timestamp::from_seconds(-1e-6),
+            Err(TimeError::DurationUnderflow)
+        ));
+        assert!(matches!(
+            Timestamp::from_seconds(1e30),
+            Err(TimeError::DurationOverflow)
+        ));
+    }
+
+    #[test]
+    fn signed_duration_since_is_negative_for_earlier_stamps() {
+        let a = Timestamp::from_nanos(1_000);
+        let b = Timestamp::from_nanos(4_000);
+
+        assert_eq!(b.signed_duration_since(a).unwrap(), 3_000);
+        assert_eq!(a.signed_duration_since(b).unwrap(), -3_000);
+        assert_eq!(a.signed_duration_since(a).unwrap(), 0);
+    }
+
+    #[test]
+    fn signed_duration_since_covers_the_full_i128_range() {
+        let zero = Timestamp::zero();
+        let max = Timestamp::from_nanos(i128::MAX.unsigned_abs());
+
+        assert_eq!(max.signed_duration_since(zero).unwrap(), i128::MAX);
+        assert_eq!(zero.signed_duration_since(max).unwrap(), -i128::MAX);
+        assert!(matches!(
+            Timestamp::from_nanos(u128::MAX).signed_duration_since(zero),
+            Err(TimeError::DurationOverflow)
+        ));
+    }
 }
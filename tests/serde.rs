@@ -1,11 +1,25 @@
 #![cfg(feature = "serde")]
 //! JSON roundtrip tests for the optional serde support.
 
+use serde::{Deserialize, Serialize};
 use transforms::{
     geometry::{Point, Quaternion, Transform, Vector3},
     time::Timestamp,
 };
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Stamped {
+    #[serde(with = "transforms::time::format::rfc3339")]
+    readable: Timestamp,
+    #[serde(with = "transforms::time::format::nanos")]
+    raw: Timestamp,
+}
+
+fn parse_rfc3339(text: &str) -> Result<Timestamp, serde_json::Error> {
+    let json = format!(r#"{{"readable":"{text}","raw":0}}"#);
+    serde_json::from_str::<Stamped>(&json).map(|stamped| stamped.readable)
+}
+
 #[test]
 fn vector3_json_roundtrip_is_exact() {
     let vector = Vector3::new(1.5, -2.25, 3.125);
@@ -95,3 +109,108 @@ fn transform_deserializes_from_handwritten_json_with_struct_field_names() {
         assert!(object.contains_key(field), "missing field {field}");
     }
 }
+
+#[test]
+fn timestamp_formats_serialize_as_rfc3339_and_raw_nanos() {
+    let stamped = Stamped {
+        readable: Timestamp::from_nanos(1_709_210_096_789_000_000),
+        raw: Timestamp::from_nanos(1_709_210_096_789_000_000),
+    };
+
+    let json = serde_json::to_string(&stamped).unwrap();
+    assert_eq!(
+        json,
+        r#"{"readable":"2024-02-29T12:34:56.789Z","raw":1709210096789000000}"#
+    );
+
+    let deserialized: Stamped = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, stamped);
+}
+
+#[test]
+fn rfc3339_trims_fraction_and_roundtrips_nanoseconds() {
+    for (nanos, text) in [
+        (0, "1970-01-01T00:00:00Z"),
+        (1, "1970-01-01T00:00:00.000000001Z"),
+        (951_782_400_500_000_000, "2000-02-29T00:00:00.5Z"),
+        (
+            253_402_300_799_999_999_999,
+            "9999-12-31T23:59:59.999999999Z",
+        ),
+    ] {
+        let stamped = Stamped {
+            readable: Timestamp::from_nanos(nanos),
+            raw: Timestamp::from_nanos(0),
+        };
+        let value = serde_json::to_value(&stamped).unwrap();
+        assert_eq!(value["readable"], text);
+        assert_eq!(parse_rfc3339(text).unwrap(), stamped.readable);
+    }
+}
+
+#[test]
+fn rfc3339_accepts_offsets_and_relaxed_separators() {
+    let expected = Timestamp::from_nanos(1_709_210_096_789_000_000);
+
+    assert_eq!(
+        parse_rfc3339("2024-02-29T14:34:56.789+02:00").unwrap(),
+        expected
+    );
+    assert_eq!(
+        parse_rfc3339("2024-02-29T07:04:56.789-05:30").unwrap(),
+        expected
+    );
+    assert_eq!(parse_rfc3339("2024-02-29 12:34:56.789z").unwrap(), expected);
+    assert_eq!(
+        parse_rfc3339("2024-02-29t12:34:56.789000000Z").unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn rfc3339_rejects_invalid_and_unrepresentable_input() {
+    for text in [
+        "",
+        "2024-02-29",
+        "2024-02-29T12:34:56",
+        "2024-02-30T12:34:56Z",
+        "2023-02-29T12:34:56Z",
+        "2024-13-01T12:34:56Z",
+        "2024-02-29T24:00:00Z",
+        "2024-02-29T12:34:60Z",
+        "2024-02-29T12:34:56.Z",
+        "2024-02-29T12:34:56.1234567891Z",
+        "2024-02-29T12:34:56+0200",
+        "2024-02-29T12:34:56Z trailing",
+        "1969-12-31T23:59:59Z",
+        "1970-01-01T00:00:00+00:01",
+    ] {
+        assert!(parse_rfc3339(text).is_err(), "accepted {text:?}");
+    }
+}
+
+#[test]
+fn rfc3339_rejects_timestamps_after_year_9999() {
+    let stamped = Stamped {
+        readable: Timestamp::from_nanos(253_402_300_800_000_000_000),
+        raw: Timestamp::from_nanos(0),
+    };
+
+    assert!(serde_json::to_string(&stamped).is_err());
+}
+
+#[test]
+fn rfc3339_rejects_timestamps_far_beyond_year_9999() {
+    let max_days = u128::from(u64::MAX) * 86_400 * 1_000_000_000;
+    for nanos in [max_days, u128::MAX] {
+        let stamped = Stamped {
+            readable: Timestamp::from_nanos(nanos),
+            raw: Timestamp::from_nanos(0),
+        };
+
+        assert!(
+            serde_json::to_string(&stamped).is_err(),
+            "serialized {nanos}"
+        );
+    }
+}